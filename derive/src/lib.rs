@@ -0,0 +1,278 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! `#[derive(FromInput)]`, re-exported by `advent_of_code`.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, Ident, LitStr};
+
+/// Derives `FromStr` from a `#[pattern("...")]` attribute.
+///
+/// Placeholders are named after the fields of a struct (`#[pattern("{start}-{end}")]`),
+/// or refer to the fields of a tuple struct in order (`{}`) or by index (`{0}`).
+/// Enums need a pattern on every variant. The first variant whose pattern matches and whose fields parse is used.
+/// Fields are parsed with their own `FromStr` implementation, errors are `advent_of_code::parsing::ParseError`s.
+#[proc_macro_derive(FromInput, attributes(pattern))]
+pub fn derive_from_input(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Placeholder {
+    /// `{}`, the next field.
+    Next,
+    /// `{0}`, a field of a tuple.
+    Index(usize),
+    /// `{name}`, a named field.
+    Name(String),
+}
+
+/// Splits a pattern like `{start}-{end}` into a pattern for `scan` (`{}-{}`) and its placeholders.
+fn split_pattern(pattern: &str) -> Result<(String, Vec<Placeholder>), String> {
+    let mut scan = String::new();
+    let mut placeholders = vec![];
+    let mut after_placeholder = false;
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                if after_placeholder {
+                    return Err("placeholders have to be separated by text".to_string());
+                }
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err("unclosed `{`".to_string()),
+                    }
+                }
+                let name = name.trim();
+                placeholders.push(match name.parse() {
+                    _ if name.is_empty() => Placeholder::Next,
+                    Ok(i) => Placeholder::Index(i),
+                    Err(_) => Placeholder::Name(name.to_string()),
+                });
+                scan.push_str("{}");
+                after_placeholder = true;
+            }
+            '}' => return Err("unmatched `}`".to_string()),
+            c => {
+                scan.push(c);
+                after_placeholder = false;
+            }
+        }
+    }
+    Ok((scan, placeholders))
+}
+
+fn pattern_attr(attrs: &[Attribute]) -> Option<syn::Result<LitStr>> {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("pattern"))
+        .map(|attr| attr.parse_args())
+}
+
+/// A field's binding and the expression that parses it from the `captures`.
+type Value = (Ident, TokenStream2);
+
+/// The pattern for `scan`, the values of the fields, and an expression that builds `path` from their bindings.
+fn constructor(
+    path: TokenStream2,
+    fields: &Fields,
+    pattern: &LitStr,
+) -> syn::Result<(String, Vec<Value>, TokenStream2)> {
+    let error = |message: String| Error::new(pattern.span(), message);
+    let (scan, placeholders) = split_pattern(&pattern.value()).map_err(error)?;
+
+    // the placeholder of every field.
+    let mut positions: Vec<Option<usize>> = vec![None; fields.len()];
+    let mut next = 0;
+    for (position, placeholder) in placeholders.iter().enumerate() {
+        let field = match (placeholder, fields) {
+            (Placeholder::Name(name), Fields::Named(named)) => named
+                .named
+                .iter()
+                .position(|f| f.ident.as_ref().is_some_and(|i| i == name))
+                .ok_or_else(|| error(format!("there is no field `{}`", name)))?,
+            (_, Fields::Named(_)) => {
+                return Err(error(
+                    "use field names as placeholders, e.g. `{start}`".to_string(),
+                ))
+            }
+            (Placeholder::Next, _) => {
+                next += 1;
+                next - 1
+            }
+            (Placeholder::Index(i), _) => *i,
+            (Placeholder::Name(name), _) => {
+                return Err(error(format!(
+                    "there is no field `{}`, use `{{}}` or `{{0}}` for unnamed fields",
+                    name
+                )))
+            }
+        };
+        match positions.get_mut(field) {
+            Some(Some(_)) => return Err(error(format!("field {} is used twice", field))),
+            Some(slot) => *slot = Some(position),
+            None => {
+                return Err(error(format!(
+                    "the pattern has more placeholders than fields ({})",
+                    fields.len()
+                )))
+            }
+        }
+    }
+
+    let parse = |field: usize| -> syn::Result<TokenStream2> {
+        match positions[field] {
+            Some(position) => {
+                Ok(quote!(::advent_of_code::parsing::parse_value(captures[#position])))
+            }
+            None => Err(error(format!(
+                "field {} is missing from the pattern",
+                fields
+                    .iter()
+                    .nth(field)
+                    .and_then(|f| f.ident.as_ref())
+                    .map_or_else(|| field.to_string(), |i| format!("`{}`", i))
+            ))),
+        }
+    };
+    let values = (0..fields.len())
+        .map(|field| Ok((binding(field), parse(field)?)))
+        .collect::<syn::Result<Vec<_>>>()?;
+    let bindings = values.iter().map(|(binding, _)| binding);
+    let tokens = match fields {
+        Fields::Named(named) => {
+            let idents = named.named.iter().map(|f| &f.ident);
+            quote!(#path { #(#idents: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#bindings),*)),
+        Fields::Unit => path,
+    };
+    Ok((scan, values, tokens))
+}
+
+/// The local variable that holds the parsed value of a field.
+fn binding(field: usize) -> Ident {
+    format_ident!("field{}", field)
+}
+
+/// Binds the captures, unless there are no fields to parse them into.
+fn captures_binding(fields: &Fields) -> TokenStream2 {
+    match fields {
+        Fields::Unit => quote!(_),
+        _ => quote!(captures),
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let missing = || Error::new_spanned(name, "add a pattern like `#[pattern(\"{start}-{end}\")]`");
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let pattern = pattern_attr(&input.attrs).ok_or_else(missing)?;
+            let (scan, values, value) = constructor(quote!(Self), &data.fields, &pattern?)?;
+            let captures = captures_binding(&data.fields);
+            let (bindings, parses): (Vec<_>, Vec<_>) = values.into_iter().unzip();
+            quote! {
+                let #captures = ::advent_of_code::parsing::scan(#scan, s)?;
+                #(let #bindings = #parses?;)*
+                Ok(#value)
+            }
+        }
+        Data::Enum(data) => {
+            let variants = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let pattern = pattern_attr(&variant.attrs).ok_or_else(|| {
+                        Error::new_spanned(ident, "every variant needs a `#[pattern(\"...\")]`")
+                    })?;
+                    let (scan, values, value) =
+                        constructor(quote!(Self::#ident), &variant.fields, &pattern?)?;
+                    let captures = captures_binding(&variant.fields);
+                    // a field that does not parse moves on to the next variant, like a pattern that does not match.
+                    let (bindings, parses): (Vec<_>, Vec<_>) = values.into_iter().unzip();
+                    let parsed = if bindings.is_empty() {
+                        quote!(return Ok(#value);)
+                    } else {
+                        quote! {
+                            if let (#(Ok(#bindings),)*) = (#(#parses,)*) {
+                                return Ok(#value);
+                            }
+                        }
+                    };
+                    Ok(quote! {
+                        if let Ok(#captures) = ::advent_of_code::parsing::scan(#scan, s) {
+                            #parsed
+                        }
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            let name = name.to_string();
+            quote! {
+                #(#variants)*
+                Err(::advent_of_code::parsing::ParseError::new(format!(
+                    "{:?} does not match any pattern of {}",
+                    s, #name
+                )))
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                name,
+                "`FromInput` can not be derived for unions",
+            ))
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::advent_of_code::parsing::ParseError;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                #body
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_pattern() {
+        assert_eq!(
+            split_pattern("{start}-{end}"),
+            Ok((
+                "{}-{}".to_string(),
+                vec![
+                    Placeholder::Name("start".into()),
+                    Placeholder::Name("end".into())
+                ]
+            ))
+        );
+        assert_eq!(
+            split_pattern("addx { } {1}"),
+            Ok((
+                "addx {} {}".to_string(),
+                vec![Placeholder::Next, Placeholder::Index(1)]
+            ))
+        );
+        assert_eq!(split_pattern("noop"), Ok(("noop".to_string(), vec![])));
+        assert!(split_pattern("{a}{b}").is_err());
+        assert!(split_pattern("{a").is_err());
+        assert!(split_pattern("a}").is_err());
+    }
+}
@@ -0,0 +1,169 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Lockfile with accepted answers, relative to the project root.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<String>,
+}
+
+/// Accepted answers, keyed by year and zero-padded day, e.g. `[2022.01]`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, DayAnswers>>);
+
+impl Answers {
+    pub fn parse(contents: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(contents)
+    }
+
+    /// Loads the lockfile. A missing file is treated as an empty lockfile.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                Self::parse(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let contents =
+            toml::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, contents)
+    }
+
+    pub fn get(&self, year: i32, day: u8, part: u8) -> Option<&str> {
+        let answers = self.0.get(&year.to_string())?.get(&format!("{:02}", day))?;
+        match part {
+            1 => answers.part_one.as_deref(),
+            2 => answers.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, year: i32, day: u8, part: u8, answer: String) {
+        let answers = self
+            .0
+            .entry(year.to_string())
+            .or_default()
+            .entry(format!("{:02}", day))
+            .or_default();
+        match part {
+            1 => answers.part_one = Some(answer),
+            2 => answers.part_two = Some(answer),
+            _ => panic!("invalid part: {}", part),
+        }
+    }
+}
+
+/// Returns the accepted answer for a part, if the project's lockfile has one.
+pub fn locked(year: i32, day: u8, part: u8) -> Option<String> {
+    let answers = Answers::load(&crate::project_dir().join(ANSWERS_FILE)).ok()?;
+    answers.get(year, day, part).map(str::to_string)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer matches the accepted answer.
+    Pass,
+    /// An answer was accepted, but the solution did not produce one.
+    Fail,
+    /// The answer differs from the accepted answer.
+    Changed,
+    /// No answer has been accepted for this part yet.
+    Missing,
+}
+
+impl Verdict {
+    pub fn new(locked: Option<&str>, answer: Option<&str>) -> Self {
+        match (locked, answer) {
+            (None, _) => Verdict::Missing,
+            (Some(_), None) => Verdict::Fail,
+            (Some(locked), Some(answer)) if locked == answer => Verdict::Pass,
+            (Some(_), Some(_)) => Verdict::Changed,
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Verdict::Pass | Verdict::Missing)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail => "fail",
+            Verdict::Changed => "changed",
+            Verdict::Missing => "missing",
+        };
+        f.pad(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse(
+            r#"
+[2021.01]
+part_one = "1466"
+
+[2022.01]
+part_one = "24000"
+part_two = "45000"
+
+[2022.05]
+part_one = "CMZ"
+"#,
+        )
+        .unwrap();
+        assert_eq!(answers.get(2022, 1, 1), Some("24000"));
+        assert_eq!(answers.get(2022, 1, 2), Some("45000"));
+        assert_eq!(answers.get(2022, 5, 1), Some("CMZ"));
+        assert_eq!(answers.get(2022, 5, 2), None);
+        assert_eq!(answers.get(2022, 6, 1), None);
+        assert_eq!(answers.get(2021, 1, 1), Some("1466"));
+        assert_eq!(answers.get(2020, 1, 1), None);
+    }
+
+    #[test]
+    fn test_roundtrip_answers() {
+        let mut answers = Answers::default();
+        answers.set(2022, 10, 1, "13140".into());
+        answers.set(2022, 2, 2, "12".into());
+        let contents = toml::to_string(&answers).unwrap();
+        assert_eq!(
+            contents,
+            "[2022.02]\npart_two = \"12\"\n\n[2022.10]\npart_one = \"13140\"\n"
+        );
+        assert_eq!(Answers::parse(&contents).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(Verdict::new(Some("1"), Some("1")), Verdict::Pass);
+        assert_eq!(Verdict::new(Some("1"), Some("2")), Verdict::Changed);
+        assert_eq!(Verdict::new(Some("1"), None), Verdict::Fail);
+        assert_eq!(Verdict::new(None, Some("1")), Verdict::Missing);
+        assert_eq!(Verdict::new(None, None), Verdict::Missing);
+    }
+}
@@ -0,0 +1,407 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::config::Config;
+use chrono::{DateTime, Datelike, Utc};
+use std::env;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Overrides the server, e.g. to test against a local stand-in.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Session cookie, takes precedence over the session file.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Session file in the home directory, shared with aoc-cli.
+pub const SESSION_FILE: &str = ".adventofcode.session";
+
+/// Responses are cached here, relative to the project root.
+pub const CACHE_DIR: &str = ".aoc_cache";
+
+/// Name and version of this project, sent as the `User-Agent`.
+pub const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+pub enum Error {
+    /// Neither the environment variable nor the session file is set.
+    MissingSession,
+    /// The server answered with an error status.
+    Http {
+        status: u16,
+        body: String,
+    },
+    /// The server could not be reached.
+    Transport(String),
+    Io(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingSession => write!(
+                f,
+                "no session cookie found. Set {} or paste your session cookie into \"~/{}\" (or the `session_file` of \"{}\")",
+                SESSION_ENV, SESSION_FILE, crate::config::CONFIG_FILE
+            ),
+            Error::Http { status: 400, .. } => {
+                write!(f, "the session cookie is invalid or has expired")
+            }
+            Error::Http { status: 404, .. } => write!(f, "the puzzle is not unlocked yet"),
+            Error::Http { status, body } => write!(
+                f,
+                "server responded with status {}: {}",
+                status,
+                body.lines().next().unwrap_or_default()
+            ),
+            Error::Transport(e) => write!(f, "could not reach the server: {}", e),
+            Error::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// The most recent event: the current year in December, the previous year otherwise.
+pub fn current_event_year(now: DateTime<Utc>) -> i32 {
+    if now.month() == 12 {
+        now.year()
+    } else {
+        now.year() - 1
+    }
+}
+
+/// Advent of Code asks automated tools to identify themselves with a way to contact their user:
+/// the `contact` of `aoc.toml`, or else the `repository` of `Cargo.toml`.
+pub fn user_agent(contact: Option<&str>) -> String {
+    let contact = contact.or(Some(env!("CARGO_PKG_REPOSITORY")).filter(|r| !r.is_empty()));
+    match contact {
+        Some(contact) => format!("{} (+{})", USER_AGENT, contact),
+        None => USER_AGENT.to_string(),
+    }
+}
+
+/// Reads the session cookie from `AOC_SESSION` or the session file, `~/.adventofcode.session` by default.
+pub fn read_session(config: &Config) -> Result<String, Error> {
+    if let Ok(session) = env::var(SESSION_ENV) {
+        return Ok(session.trim().to_string());
+    }
+    let path = config.session_file().ok_or(Error::MissingSession)?;
+    let session = fs::read_to_string(path).map_err(|_| Error::MissingSession)?;
+    match session.trim() {
+        "" => Err(Error::MissingSession),
+        session => Ok(session.to_string()),
+    }
+}
+
+/// Minimal client for the Advent of Code website.
+pub struct Client {
+    base_url: String,
+    session: String,
+    user_agent: String,
+    agent: ureq::Agent,
+    cache_dir: Option<PathBuf>,
+    refresh: bool,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            user_agent: user_agent(None),
+            agent: ureq::AgentBuilder::new().timeout(TIMEOUT).build(),
+            cache_dir: None,
+            refresh: false,
+        }
+    }
+
+    /// Creates a client for `AOC_BASE_URL` (or the real website) with the user's session cookie and contact,
+    /// caching responses in the project.
+    pub fn from_env() -> Result<Self, Error> {
+        let config = crate::config::get();
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let client = Client::new(&base_url, &read_session(config)?);
+        Ok(client
+            .with_contact(config.contact.as_deref())
+            .with_cache(crate::project_dir().join(CACHE_DIR)))
+    }
+
+    /// Adds a way to contact the user to the `User-Agent`, see [`user_agent`].
+    pub fn with_contact(mut self, contact: Option<&str>) -> Self {
+        self.user_agent = user_agent(contact);
+        self
+    }
+
+    /// Caches successful `GET` responses in `dir`.
+    pub fn with_cache(mut self, dir: PathBuf) -> Self {
+        self.cache_dir = Some(dir);
+        self
+    }
+
+    /// Ignores cached responses. Fresh responses are still written to the cache.
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
+    fn cache_path(&self, path: &str) -> Option<PathBuf> {
+        let key = path.trim_start_matches('/').replace('/', "_");
+        self.cache_dir.as_ref().map(|dir| dir.join(key))
+    }
+
+    fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, Error> {
+        match response {
+            Ok(response) => {
+                let mut body = String::new();
+                response.into_reader().read_to_string(&mut body)?;
+                Ok(body)
+            }
+            Err(ureq::Error::Status(status, response)) => Err(Error::Http {
+                status,
+                body: response.into_string().unwrap_or_default(),
+            }),
+            Err(ureq::Error::Transport(e)) => Err(Error::Transport(e.to_string())),
+        }
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Fetches `path`, using the cache if possible.
+    pub fn get(&self, path: &str) -> Result<String, Error> {
+        self.get_cached(path, None)
+    }
+
+    /// Fetches `path`, using a cached response only if it is younger than `max_age`.
+    pub fn get_recent(&self, path: &str, max_age: Duration) -> Result<String, Error> {
+        self.get_cached(path, Some(max_age))
+    }
+
+    fn get_cached(&self, path: &str, max_age: Option<Duration>) -> Result<String, Error> {
+        let cache_path = self.cache_path(path);
+        if let (Some(cache_path), false) = (&cache_path, self.refresh) {
+            let fresh = max_age.is_none_or(|max_age| {
+                fs::metadata(cache_path)
+                    .and_then(|m| m.modified())
+                    .is_ok_and(|modified| modified.elapsed().is_ok_and(|age| age < max_age))
+            });
+            if let (true, Ok(body)) = (fresh, fs::read_to_string(cache_path)) {
+                return Ok(body);
+            }
+        }
+
+        let url = format!("{}{}", self.base_url, path);
+        let response = self
+            .agent
+            .get(&url)
+            .set("User-Agent", &self.user_agent)
+            .set("Cookie", &self.cookie())
+            .call();
+        let body = Self::read_response(response)?;
+
+        if let Some(cache_path) = cache_path {
+            if let Some(dir) = cache_path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(cache_path, &body)?;
+        }
+        Ok(body)
+    }
+
+    /// Posts a form to `path`. Responses are never cached.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, Error> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self
+            .agent
+            .post(&url)
+            .set("User-Agent", &self.user_agent)
+            .set("Cookie", &self.cookie())
+            .send_form(form);
+        Self::read_response(response)
+    }
+
+    /// Fetches the puzzle input of a day.
+    pub fn input(&self, year: i32, day: u8) -> Result<String, Error> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+}
+
+/// A local stand-in for the website that answers each request with a canned response.
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    /// A request as received by the server.
+    pub struct Request {
+        pub head: String,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.head.lines().find_map(|l| {
+                let (key, value) = l.split_once(':')?;
+                key.eq_ignore_ascii_case(name).then(|| value.trim())
+            })
+        }
+    }
+
+    /// Starts a server that answers one request per response, in order, and returns its url.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, response) in responses {
+                let Ok((stream, _)) = listener.accept() else { break };
+                let mut reader = BufReader::new(stream);
+
+                let mut head = String::new();
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        break;
+                    }
+                    head.push_str(&line);
+                }
+                let mut request = Request {
+                    head,
+                    body: String::new(),
+                };
+                let length: usize = request
+                    .header("Content-Length")
+                    .map_or(0, |l| l.parse().unwrap());
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                request.body = String::from_utf8(body).unwrap();
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    response.len(),
+                    response
+                )
+                .unwrap();
+
+                #[allow(unused_must_use)]
+                {
+                    sender.send(request);
+                }
+            }
+        });
+
+        (url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use std::env::temp_dir;
+
+    #[test]
+    fn test_input_request() {
+        let (url, requests) = mock::serve(vec![(200, "1000\n2000\n"), (200, "")]);
+        let client = Client::new(&url, "secret");
+
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+
+        let request = requests.recv().unwrap();
+        assert!(request.head.starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert_eq!(request.header("Cookie"), Some("session=secret"));
+        assert_eq!(
+            request.header("User-Agent"),
+            Some(user_agent(None).as_str())
+        );
+
+        let client = client.with_contact(Some("me@example.com"));
+        client
+            .post("/2022/day/1/answer", &[("level", "1")])
+            .unwrap();
+        let request = requests.recv().unwrap();
+        assert_eq!(
+            request.header("User-Agent"),
+            Some(format!("{} (+me@example.com)", USER_AGENT).as_str())
+        );
+    }
+
+    #[test]
+    fn test_error_status() {
+        let (url, _requests) = mock::serve(vec![
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+        ]);
+        let client = Client::new(&url, "expired");
+
+        let err = client.input(2022, 1).unwrap_err();
+        assert!(matches!(err, Error::Http { status: 400, .. }));
+        assert_eq!(
+            err.to_string(),
+            "the session cookie is invalid or has expired"
+        );
+
+        let err = client.input(2022, 25).unwrap_err();
+        assert!(matches!(err, Error::Http { status: 404, .. }));
+    }
+
+    #[test]
+    fn test_cache() {
+        let cache_dir = temp_dir().join(format!("aoc_cache_test_{}", std::process::id()));
+        #[allow(unused_must_use)]
+        {
+            fs::remove_dir_all(&cache_dir);
+        }
+
+        // the server answers for the first request, the refresh and the outdated response.
+        let (url, requests) = mock::serve(vec![(200, "first"), (200, "second"), (200, "third")]);
+        let client = Client::new(&url, "secret").with_cache(cache_dir.clone());
+        assert_eq!(client.input(2022, 2).unwrap(), "first");
+        assert_eq!(client.input(2022, 2).unwrap(), "first");
+
+        let client = client.refresh(true);
+        assert_eq!(client.input(2022, 2).unwrap(), "second");
+
+        let client = client.refresh(false);
+        let path = "/2022/day/2/input";
+        assert_eq!(
+            client.get_recent(path, Duration::from_secs(3600)).unwrap(),
+            "second"
+        );
+        assert_eq!(client.get_recent(path, Duration::ZERO).unwrap(), "third");
+        assert_eq!(requests.iter().count(), 3);
+
+        fs::remove_dir_all(&cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_current_event_year() {
+        let december = Utc.with_ymd_and_hms(2022, 12, 3, 5, 0, 0).unwrap();
+        assert_eq!(current_event_year(december), 2022);
+        let november = Utc.with_ymd_and_hms(2023, 11, 30, 5, 0, 0).unwrap();
+        assert_eq!(current_event_year(november), 2022);
+    }
+}
@@ -0,0 +1,121 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Set to enable bench mode for `cargo solve`, like passing `--bench`.
+pub const BENCH_ENV: &str = "AOC_BENCH";
+/// Time budget per part in milliseconds.
+pub const BENCH_BUDGET_ENV: &str = "AOC_BENCH_BUDGET_MS";
+
+const DEFAULT_BUDGET: Duration = Duration::from_millis(500);
+const MIN_ITERATIONS: usize = 10;
+const MAX_ITERATIONS: usize = 1_000_000;
+
+/// Summary of repeated timings of a single part, in nanoseconds.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: f64,
+    pub stddev_ns: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [u64]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_unstable();
+
+        let n = samples.len();
+        let median_ns = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        let mean_ns = samples.iter().map(|&s| s as f64).sum::<f64>() / n as f64;
+        let variance = samples
+            .iter()
+            .map(|&s| (s as f64 - mean_ns).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Some(Stats {
+            iterations: n,
+            min_ns: samples[0],
+            median_ns,
+            mean_ns,
+            stddev_ns: variance.sqrt(),
+        })
+    }
+}
+
+/// Returns the time budget per part if bench mode was requested, by `bench` or by the environment.
+pub fn budget(bench: bool) -> Option<Duration> {
+    let enabled = bench || env::var_os(BENCH_ENV).is_some_and(|v| v != "0");
+    if !enabled {
+        return None;
+    }
+    let budget = env::var(BENCH_BUDGET_ENV)
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .map_or(DEFAULT_BUDGET, Duration::from_millis);
+    Some(budget)
+}
+
+/// Warms up, then runs `func` repeatedly until `budget` is spent.
+/// Returns the result of the last run and the timing statistics.
+/// Calls and results go through `black_box`, so the compiler can not hoist or skip the work.
+pub fn run<T>(func: impl Fn() -> T, budget: Duration) -> (T, Stats) {
+    // spend a tenth of the budget warming caches and branch predictors.
+    let warmup = Instant::now();
+    let mut result = black_box(black_box(&func)());
+    while warmup.elapsed() < budget / 10 {
+        result = black_box(black_box(&func)());
+    }
+
+    let mut samples = vec![];
+    let start = Instant::now();
+    while samples.len() < MAX_ITERATIONS
+        && (samples.len() < MIN_ITERATIONS || start.elapsed() < budget)
+    {
+        let timer = Instant::now();
+        result = black_box(black_box(&func)());
+        samples.push(timer.elapsed().as_nanos() as u64);
+    }
+
+    (result, Stats::from_samples(&mut samples).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let stats = Stats::from_samples(&mut [40, 10, 30, 20]).unwrap();
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.min_ns, 10);
+        assert_eq!(stats.median_ns, 25);
+        assert!((stats.mean_ns - 25.0).abs() < 1.0e-6);
+        assert!((stats.stddev_ns - 125_f64.sqrt()).abs() < 1.0e-6);
+
+        let stats = Stats::from_samples(&mut [7, 3, 5]).unwrap();
+        assert_eq!(stats.median_ns, 5);
+
+        assert_eq!(Stats::from_samples(&mut []), None);
+    }
+
+    #[test]
+    fn test_run_collects_minimum_iterations() {
+        let (result, stats) = run(|| 42, Duration::ZERO);
+        assert_eq!(result, 42);
+        assert_eq!(stats.iterations, MIN_ITERATIONS);
+    }
+}
@@ -2,104 +2,123 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
+use advent_of_code::aoc::Client;
+use advent_of_code::config;
+use advent_of_code::puzzle;
 use std::{fs, process};
 
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: Option<i32>,
+    force: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
-        day: args.free_from_str()?,
+        force: args.contains(["-f", "--force"]),
         year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.free_from_str()?,
     })
 }
 
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
-    {
-        fs::remove_file(path);
-    }
-}
-
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
-}
-
 fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
-
     let args = match parse_args() {
         Ok(args) => args,
         Err(e) => {
             eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
-    }
+    let config = match config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to load configuration: {}", e);
+            process::exit(1);
+        }
+    };
+    let year = match args.year.map_or_else(|| config.year(), Ok) {
+        Ok(year) => year,
+        Err(e) => {
+            eprintln!(
+                "Failed to determine the year: {}, or pass `--year <year>`.",
+                e
+            );
+            process::exit(1);
+        }
+    };
 
-    let mut cmd_args = vec![];
+    let input_path = advent_of_code::input_path("inputs", year, args.day);
 
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+    // `cargo scaffold` creates an empty input file, which may be overwritten.
+    let exists = fs::metadata(&input_path).is_ok_and(|m| m.len() > 0);
+    if exists && !args.force {
+        eprintln!(
+            "Input file \"{}\" already exists. Use `--force` to download it again.",
+            input_path.display()
+        );
+        process::exit(1);
     }
 
-    cmd_args.append(&mut vec![
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
+    let client = match Client::from_env() {
+        Ok(client) => client.refresh(args.force),
+        Err(e) => {
+            eprintln!("Failed to create client: {}", e);
+            process::exit(1);
+        }
+    };
 
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
+    println!("Downloading input for day {}, {}...", args.day, year);
 
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
-            }
-        }
+    let input = match client.input(year, args.day) {
+        Ok(input) => input,
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("Failed to download input: {}", e);
+            process::exit(1);
         }
-    }
+    };
 
-    match fs::copy(&tmp_file_path, &input_path) {
+    let written = fs::create_dir_all(input_path.parent().unwrap())
+        .and_then(|_| fs::write(&input_path, input));
+    match written {
         Ok(_) => {
             println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
+            println!(
+                "🎄 Successfully wrote input to \"{}\".",
+                input_path.display()
+            );
         }
         Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("could not write input file: {}", e);
+            process::exit(1);
+        }
+    }
+
+    // the description is a convenience, failing to fetch it does not fail the download.
+    let puzzle_path = puzzle::puzzle_path(year, args.day);
+    let markdown = match puzzle::fetch(&client, year, args.day) {
+        Ok(markdown) => markdown,
+        Err(e) => {
+            eprintln!("could not download puzzle description: {}", e);
+            return;
+        }
+    };
+    let written = fs::create_dir_all(puzzle_path.parent().unwrap())
+        .and_then(|_| fs::write(&puzzle_path, &markdown));
+    match written {
+        Ok(_) => println!(
+            "🎄 Successfully wrote puzzle description to \"{}\".",
+            puzzle_path.display()
+        ),
+        Err(e) => eprintln!("could not write puzzle description: {}", e),
+    }
+
+    // fill in the examples of a scaffolded day.
+    if advent_of_code::days::get(year, args.day).is_some() {
+        match puzzle::save_examples(year, args.day, &markdown) {
+            Ok(examples) => puzzle::print_examples(year, &examples),
+            Err(e) => eprintln!("could not extract examples: {}", e),
         }
     }
 }
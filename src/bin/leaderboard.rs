@@ -0,0 +1,237 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::aoc::{self, Client};
+use advent_of_code::config;
+use advent_of_code::leaderboard::{self, Leaderboard, Scoring};
+use advent_of_code::unlock::format_countdown;
+use advent_of_code::{ANSI_BOLD, ANSI_RESET};
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
+
+struct Args {
+    file: Option<PathBuf>,
+    id: Option<u64>,
+    year: Option<i32>,
+    day: Option<u8>,
+    scoring: Scoring,
+    refresh: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        file: args.opt_value_from_str("--file")?,
+        id: args.opt_value_from_str("--id")?,
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.opt_value_from_str(["-d", "--day"])?,
+        scoring: args
+            .opt_value_from_str(["-s", "--scoring"])?
+            .unwrap_or(Scoring::Local),
+        refresh: args.contains(["-r", "--refresh"]),
+    })
+}
+
+fn format_time(time: Option<Duration>) -> String {
+    time.map_or_else(|| "-".to_string(), format_countdown)
+}
+
+/// Width of a name column: the longest name, at least as wide as the header.
+fn name_width<'a>(header: &str, names: impl Iterator<Item = &'a String>) -> usize {
+    names
+        .map(|n| n.chars().count())
+        .chain([header.len()])
+        .max()
+        .unwrap()
+}
+
+fn print_ranking(board: &Leaderboard, scoring: Scoring) {
+    let ranking = leaderboard::ranking(board, scoring);
+    let w = name_width("Name", ranking.iter().map(|s| &s.name));
+    println!(
+        "{}{:<5} {:<w$} {:<6} Score ({}){}",
+        ANSI_BOLD,
+        "Rank",
+        "Name",
+        "Stars",
+        scoring,
+        ANSI_RESET,
+        w = w
+    );
+    for (rank, standing) in ranking.iter().enumerate() {
+        println!(
+            "{:<5} {:<w$} {:<6} {}",
+            rank + 1,
+            standing.name,
+            standing.stars,
+            standing.score,
+            w = w
+        );
+    }
+}
+
+/// One row per day: how many members got each star, the fastest member and the median time between the stars.
+fn print_days(board: &Leaderboard) {
+    let rows: Vec<(u8, Vec<leaderboard::DayTimes>)> = board
+        .days()
+        .into_iter()
+        .map(|day| (day, leaderboard::day_times(board, day)))
+        .collect();
+    let w = name_width(
+        "Fastest",
+        rows.iter()
+            .flat_map(|(_, times)| &times[..1])
+            .map(|t| &t.name),
+    );
+    println!(
+        "{}{:<4} {:<7} {:<w$} {:<12} Median delta{}",
+        ANSI_BOLD,
+        "Day",
+        "Stars",
+        "Fastest",
+        "Time",
+        ANSI_RESET,
+        w = w
+    );
+    for (day, times) in rows {
+        let both = times.iter().filter(|t| t.part_two.is_some()).count();
+        let mut deltas: Vec<Duration> = times.iter().filter_map(|t| t.delta()).collect();
+        deltas.sort_unstable();
+        let fastest = &times[0];
+        println!(
+            "{:<4} {:<7} {:<w$} {:<12} {}",
+            format!("{:02}", day),
+            format!("{}/{}", times.len(), both),
+            fastest.name,
+            format_time(fastest.part_two.or(fastest.part_one)),
+            format_time(deltas.get(deltas.len() / 2).copied()),
+            w = w
+        );
+    }
+}
+
+fn print_day(board: &Leaderboard, day: u8) {
+    let times = leaderboard::day_times(board, day);
+    if times.is_empty() {
+        println!("Nobody has a star for day {} yet.", day);
+        return;
+    }
+    let w = name_width("Name", times.iter().map(|t| &t.name));
+    println!(
+        "{}{:<w$} {:<12} {:<12} Delta{}",
+        ANSI_BOLD,
+        "Name",
+        "Part 1",
+        "Part 2",
+        ANSI_RESET,
+        w = w
+    );
+    for t in &times {
+        println!(
+            "{:<w$} {:<12} {:<12} {}",
+            t.name,
+            format_time(t.part_one),
+            format_time(t.part_two),
+            format_time(t.delta()),
+            w = w
+        );
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let json = match &args.file {
+        Some(path) => fs::read_to_string(path).unwrap_or_else(|e| {
+            eprintln!("Failed to read \"{}\": {}", path.display(), e);
+            process::exit(1);
+        }),
+        None => {
+            let config = match config::load() {
+                Ok(config) => config,
+                Err(e) => {
+                    eprintln!("Failed to load configuration: {}", e);
+                    process::exit(1);
+                }
+            };
+            let year = match args.year.map_or_else(|| config.year(), Ok) {
+                Ok(year) => year,
+                Err(e) => {
+                    eprintln!(
+                        "Failed to determine the year: {}, or pass `--year <year>`.",
+                        e
+                    );
+                    process::exit(1);
+                }
+            };
+            let id = match args.id.map_or_else(|| config.leaderboard(), Ok) {
+                Ok(id) => id,
+                Err(e) => {
+                    eprintln!(
+                        "Failed to determine the leaderboard: {}, or pass `--id <id>`.",
+                        e
+                    );
+                    process::exit(1);
+                }
+            };
+            let client = match Client::from_env() {
+                Ok(client) => client.refresh(args.refresh),
+                Err(e) => {
+                    eprintln!("Failed to create client: {}", e);
+                    process::exit(1);
+                }
+            };
+            match leaderboard::fetch(&client, year, id) {
+                Ok(json) => json,
+                Err(aoc::Error::Http { status: 404, .. }) => {
+                    eprintln!(
+                        "Failed to download leaderboard: leaderboard {} of {} does not exist or you are not a member of it.",
+                        id, year
+                    );
+                    process::exit(1);
+                }
+                Err(e) => {
+                    eprintln!("Failed to download leaderboard: {}", e);
+                    process::exit(1);
+                }
+            }
+        }
+    };
+
+    let board = match Leaderboard::parse(&json) {
+        Ok(board) => board,
+        Err(e) => {
+            eprintln!(
+                "Failed to read leaderboard: {}. Check the leaderboard id and that you are a member of it.",
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    println!(
+        "🎄 Private leaderboard of {} with {} members.",
+        board.year,
+        board.members.len()
+    );
+    println!("---");
+    match args.day {
+        Some(day) => print_day(&board, day),
+        None => {
+            print_ranking(&board, args.scoring);
+            if !board.days().is_empty() {
+                println!("---");
+                print_days(&board);
+            }
+        }
+    }
+}
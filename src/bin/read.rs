@@ -0,0 +1,105 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers;
+use advent_of_code::aoc::Client;
+use advent_of_code::config;
+use advent_of_code::project_dir;
+use advent_of_code::puzzle;
+use advent_of_code::submit::{self, Outcome, SUBMISSIONS_FILE};
+use std::{fs, process};
+
+struct Args {
+    day: u8,
+    year: Option<i32>,
+    refresh: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        refresh: args.contains(["-r", "--refresh"]),
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.free_from_str()?,
+    })
+}
+
+/// Part one counts as solved once its answer is accepted or was submitted successfully.
+fn part_one_solved(year: i32, day: u8) -> bool {
+    if answers::locked(year, day, 1).is_some() {
+        return true;
+    }
+    let attempts = submit::read_attempts(&project_dir().join(SUBMISSIONS_FILE)).unwrap_or_default();
+    attempts
+        .iter()
+        .any(|a| a.year == year && a.day == day && a.part == 1 && a.outcome == Outcome::Correct)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let config = match config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to load configuration: {}", e);
+            process::exit(1);
+        }
+    };
+    let year = match args.year.map_or_else(|| config.year(), Ok) {
+        Ok(year) => year,
+        Err(e) => {
+            eprintln!(
+                "Failed to determine the year: {}, or pass `--year <year>`.",
+                e
+            );
+            process::exit(1);
+        }
+    };
+    let path = puzzle::puzzle_path(year, args.day);
+    let stored = fs::read_to_string(&path).ok();
+
+    // part two only shows up on the puzzle page once part one is solved.
+    let missing_part_two = stored
+        .as_deref()
+        .is_some_and(|md| !puzzle::has_part_two(md) && part_one_solved(year, args.day));
+
+    let markdown = match stored {
+        Some(stored) if !missing_part_two && !args.refresh => stored,
+        stored => {
+            let fetched = Client::from_env().and_then(|client| {
+                let client = client.refresh(missing_part_two || args.refresh);
+                puzzle::fetch(&client, year, args.day)
+            });
+            match (fetched, stored) {
+                (Ok(markdown), _) => {
+                    let written = fs::create_dir_all(path.parent().unwrap())
+                        .and_then(|_| fs::write(&path, &markdown));
+                    if let Err(e) = written {
+                        eprintln!("could not write \"{}\": {}", path.display(), e);
+                    }
+                    markdown
+                }
+                (Err(e), Some(stored)) => {
+                    eprintln!(
+                        "Failed to fetch puzzle description, showing stored copy: {}",
+                        e
+                    );
+                    stored
+                }
+                (Err(e), None) => {
+                    eprintln!("Failed to fetch puzzle description: {}", e);
+                    process::exit(1);
+                }
+            }
+        }
+    };
+
+    print!("{}", puzzle::render(&markdown));
+}
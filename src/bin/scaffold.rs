@@ -2,93 +2,268 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::answers::DayAnswers;
+use advent_of_code::config::{self, Config};
+use advent_of_code::manifest::{self, MANIFEST_FILE};
+use advent_of_code::project_dir;
+use advent_of_code::puzzle;
+use advent_of_code::template::{self, Context, Parser};
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+struct Args {
+    day: u8,
+    year: Option<i32>,
+    answer_one: String,
+    answer_two: Option<String>,
+    parser: Parser,
+    template: Option<PathBuf>,
+    update: bool,
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        update: args.contains("--update") || args.contains(["-f", "--force"]),
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        answer_one: args
+            .opt_value_from_fn("--answer", template::parse_answer_type)?
+            .unwrap_or_else(|| "u32".to_string()),
+        answer_two: args.opt_value_from_fn("--answer-two", template::parse_answer_type)?,
+        parser: args.opt_value_from_str("--parser")?.unwrap_or_default(),
+        template: args.opt_value_from_str("--template")?,
+        day: args.free_from_str()?,
+    })
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+/// Renders the module from the template given by `--template`, or the configured one.
+fn render_module(args: &Args, config: &Config, year: i32) -> Result<String, String> {
+    let path = args
+        .template
+        .clone()
+        .unwrap_or_else(|| config.template_path());
+    let template = fs::read_to_string(&path)
+        .map_err(|e| format!("could not read template \"{}\": {}", path.display(), e))?;
+
+    let context = Context {
+        day: args.day,
+        year,
+        answer_two: args
+            .answer_two
+            .clone()
+            .unwrap_or_else(|| args.answer_one.clone()),
+        answer_one: args.answer_one.clone(),
+        parser: args.parser,
+    };
+    template::render(&template, &context)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Regenerates the test block of an existing module, leaving the solution untouched.
+fn update_module(path: &Path, module: &str) -> Result<(), String> {
+    let existing = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let updated = template::update_test_block(&existing, module)?;
+    fs::write(path, updated).map_err(|e| e.to_string())
+}
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
-    }
+/// Paths are printed relative to the project root, the way they are configured.
+fn relative(path: &Path) -> String {
+    path.strip_prefix(project_dir())
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
-    }
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
+    fs::create_dir_all(path.parent().unwrap())?;
+    OpenOptions::new().write(true).create_new(true).open(path)
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    fs::create_dir_all(path.parent().unwrap())?;
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// Lists the example without expected answers, so both parts are expected to be unsolved.
+fn add_manifest_entry(path: &Path, name: &str) -> Result<bool, std::io::Error> {
+    let manifest = fs::read_to_string(path).unwrap_or_default();
+    let parsed = manifest::parse_manifest(&manifest)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    if parsed.contains_key(name) {
+        return Ok(false);
+    }
+    let updated = manifest::add_entry(&manifest, name, &DayAnswers::default())
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    fs::write(path, updated)?;
+    Ok(true)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// Declares the module in the registry of its year and adds it to `DAYS`.
+fn register_day(path: &Path, day_padded: &str) -> Result<bool, std::io::Error> {
+    let registry = fs::read_to_string(path)?;
+    let module = format!("day{}", day_padded);
+    if registry
+        .lines()
+        .any(|l| l == format!("pub mod {};", module))
+    {
+        return Ok(false);
+    }
+
+    let entry = format!("Day::new::<{}::Day{}>()", module, day_padded);
+    let updated =
+        template::add_to_registry(&registry, &module, "DAYS", &entry).ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "unexpected registry")
+        })?;
+    fs::write(path, updated)?;
+    Ok(true)
+}
+
+/// Creates the registry of a new year and adds it to `YEARS`.
+fn register_year(config: &Config, year: i32) -> Result<bool, std::io::Error> {
+    let path = config.days_dir(year).join("mod.rs");
+    if path.exists() {
+        return Ok(false);
+    }
+    let invalid = |e: String| std::io::Error::new(std::io::ErrorKind::InvalidData, e);
+
+    let years_path = config.years_registry();
+    let years = fs::read_to_string(&years_path)?;
+    let module = format!("y{}", year);
+    let entry = format!("Year::new({}, {}::DAYS)", year, module);
+    let updated = template::add_to_registry(&years, &module, "YEARS", &entry)
+        .ok_or_else(|| invalid("unexpected registry".to_string()))?;
+
+    let context = Context {
+        day: 0,
+        year,
+        answer_one: String::new(),
+        answer_two: String::new(),
+        parser: Parser::default(),
+    };
+    let registry = template::render(template::YEAR_REGISTRY, &context).map_err(invalid)?;
+    safe_create_file(&path)?.write_all(registry.as_bytes())?;
+    fs::write(years_path, updated)?;
+    Ok(true)
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::MissingArgument) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    let day = args.day;
+
+    let config = match config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to load configuration: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let year = match args.year.map_or_else(|| config.year(), Ok) {
+        Ok(year) => year,
+        Err(e) => {
+            eprintln!(
+                "Failed to determine the year: {}, or pass `--year <year>`.",
+                e
+            );
+            process::exit(1);
+        }
     };
 
     let day_padded = format!("{:02}", day);
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let input_path = advent_of_code::input_path("inputs", year, day);
+    let example_path = advent_of_code::input_path("examples", year, day);
+    let module_path = config.days_dir(year).join(format!("day{}.rs", day_padded));
+    let registry_path = config.days_dir(year).join("mod.rs");
+    let manifest_path = config.examples_dir(year).join(MANIFEST_FILE);
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    let module = match render_module(&args, config, year) {
+        Ok(module) => module,
         Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
+            eprintln!("Failed to render template: {}", e);
             process::exit(1);
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    if args.update {
+        match update_module(&module_path, &module) {
+            Ok(_) => {
+                println!(
+                    "Updated tests of module file \"{}\"",
+                    relative(&module_path)
+                );
+            }
+            Err(e) => {
+                eprintln!("Failed to update module file: {}", e);
+                process::exit(1);
+            }
+        }
+    } else {
+        let mut file = match safe_create_file(&module_path) {
+            Ok(file) => file,
+            Err(e) => {
+                eprintln!("Failed to create module file: {}", e);
+                eprintln!("Use `--update` to regenerate the tests of an existing module.");
+                process::exit(1);
+            }
+        };
+
+        match file.write_all(module.as_bytes()) {
+            Ok(_) => {
+                println!("Created module file \"{}\"", relative(&module_path));
+            }
+            Err(e) => {
+                eprintln!("Failed to write module contents: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    match register_year(config, year) {
+        Ok(true) => {
+            println!(
+                "Registered year {} in \"{}\"",
+                year,
+                relative(&config.years_registry())
+            );
+        }
+        Ok(false) => (),
+        Err(e) => {
+            eprintln!("Failed to register year: {}", e);
+            process::exit(1);
+        }
+    }
+
+    match register_day(&registry_path, &day_padded) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Registered module in \"{}\"", relative(&registry_path));
         }
         Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
+            eprintln!("Failed to register module: {}", e);
             process::exit(1);
         }
     }
 
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", relative(&input_path));
         }
         Err(e) => {
             eprintln!("Failed to create input file: {}", e);
@@ -98,7 +273,7 @@ fn main() {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", relative(&example_path));
         }
         Err(e) => {
             eprintln!("Failed to create example file: {}", e);
@@ -106,9 +281,39 @@ fn main() {
         }
     }
 
+    match add_manifest_entry(&manifest_path, &day_padded) {
+        Ok(true) => {
+            println!(
+                "Added example \"{}\" to \"{}\"",
+                &day_padded,
+                relative(&manifest_path)
+            );
+        }
+        Ok(false) => (),
+        Err(e) => {
+            eprintln!("Failed to update example manifest: {}", e);
+            process::exit(1);
+        }
+    }
+
+    // the description is only available if `cargo download` ran before.
+    if let Ok(markdown) = fs::read_to_string(puzzle::puzzle_path(year, day)) {
+        match puzzle::save_examples(year, day, &markdown) {
+            Ok(examples) => puzzle::print_examples(year, &examples),
+            Err(e) => {
+                eprintln!("Failed to extract examples: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    let year_flag = match config.year {
+        Some(configured) if configured == year => String::new(),
+        _ => format!(" --year {}", year),
+    };
     println!("---");
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Type `cargo solve {}{}` to run your solution.",
+        &day_padded, year_flag
     );
 }
@@ -0,0 +1,209 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::bench;
+use advent_of_code::config;
+use advent_of_code::manifest;
+use advent_of_code::runner::EXIT_NO_INPUT;
+use advent_of_code::solution::Options;
+use advent_of_code::Input;
+use std::ffi::OsString;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::{env, fs, process};
+
+/// Where the input of a day is read from.
+enum Source {
+    /// `src/inputs/<year>/<day>.txt`
+    Puzzle,
+    /// `--input <path>`
+    File(PathBuf),
+    /// `--input -`
+    Stdin,
+    /// `--example [name]`
+    Example(Option<String>),
+}
+
+struct Args {
+    day: u8,
+    year: Option<i32>,
+    source: Source,
+    part: Option<u8>,
+    bench: bool,
+}
+
+fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("expected 1 or 2, got \"{}\"", s)),
+    }
+}
+
+/// `--example` takes an optional name, which pico-args does not support.
+/// The next argument is the name unless it is a flag or the day.
+fn take_example(args: &mut Vec<OsString>) -> Option<Option<String>> {
+    let index = args.iter().position(|a| a == "--example")?;
+    args.remove(index);
+
+    let name = args
+        .get(index)
+        .and_then(|a| a.to_str())
+        .filter(|a| !a.starts_with('-') && a.parse::<u8>().is_err())
+        .map(str::to_string);
+    if name.is_some() {
+        args.remove(index);
+    }
+    Some(name)
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut raw: Vec<OsString> = env::args_os().skip(1).collect();
+    let example = take_example(&mut raw);
+
+    let mut args = pico_args::Arguments::from_vec(raw);
+    let part = args.opt_value_from_fn("--part", parse_part)?;
+    let year = args.opt_value_from_str(["-y", "--year"])?;
+    let input: Option<PathBuf> = args.opt_value_from_str("--input")?;
+    let bench = args.contains("--bench");
+    let day = args.free_from_str()?;
+    let unused = args.finish();
+    if !unused.is_empty() {
+        let unused: Vec<_> = unused.iter().map(|a| a.to_string_lossy()).collect();
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unknown arguments: {}", unused.join(" ")),
+        });
+    }
+
+    let source = match (input, example) {
+        (Some(_), Some(_)) => {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "`--input` and `--example` cannot be combined".to_string(),
+            })
+        }
+        (Some(path), None) if path.as_os_str() == "-" => Source::Stdin,
+        (Some(path), None) => Source::File(path),
+        (None, Some(name)) => Source::Example(name),
+        (None, None) => Source::Puzzle,
+    };
+
+    Ok(Args {
+        day,
+        year,
+        source,
+        part,
+        bench,
+    })
+}
+
+/// Finds an example by its full name (`09-b`) or its suffix (`b`). Defaults to the first example of the day.
+fn read_example(year: i32, day: u8, name: Option<&str>) -> Result<String, String> {
+    let examples = manifest::examples(year, day);
+    let full_name = name.map(|n| format!("{:02}-{}", day, n));
+
+    let example = examples.iter().find(|e| match name {
+        Some(name) => e.name == name || Some(&e.name) == full_name.as_ref(),
+        None => true,
+    });
+
+    match example {
+        Some(example) => Ok(example.input.clone()),
+        None => {
+            let available: Vec<&str> = examples.iter().map(|e| e.name.as_str()).collect();
+            Err(format!(
+                "No example \"{}\" for day {}. Available examples: {}",
+                name.unwrap_or_default(),
+                day,
+                if available.is_empty() {
+                    "none".to_string()
+                } else {
+                    available.join(", ")
+                }
+            ))
+        }
+    }
+}
+
+fn read_input(year: i32, day: u8, source: &Source) -> Result<Input, String> {
+    match source {
+        Source::Puzzle => {
+            let input_path = advent_of_code::input_path("inputs", year, day);
+            if !input_path.exists() {
+                eprintln!(
+                    "Input file \"{}\" does not exist. Run `cargo download {} --year {}` to download it.",
+                    input_path.display(),
+                    day,
+                    year
+                );
+                process::exit(EXIT_NO_INPUT);
+            }
+            Ok(advent_of_code::read_file("inputs", year, day))
+        }
+        Source::File(path) => fs::read_to_string(path)
+            .map(Input::from)
+            .map_err(|e| format!("Could not open input file \"{}\": {}", path.display(), e)),
+        Source::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("Could not read input from stdin: {}", e))?;
+            Ok(input.into())
+        }
+        Source::Example(name) => read_example(year, day, name.as_deref()).map(Input::from),
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!(
+                "usage: `cargo solve <day> [--year <year>] [--input <path>|-] [--example [name]] [--part 1|2] [--bench]`"
+            );
+            process::exit(1);
+        }
+    };
+
+    let config = match config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to load configuration: {}", e);
+            process::exit(1);
+        }
+    };
+    let year = match args.year.map_or_else(|| config.year(), Ok) {
+        Ok(year) => year,
+        Err(e) => {
+            eprintln!(
+                "Failed to determine the year: {}, or pass `--year <year>`.",
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    let Some(solution) = advent_of_code::days::get(year, args.day) else {
+        eprintln!(
+            "No solution registered for day {} of {}. Try running `cargo scaffold {} --year {}`.",
+            args.day, year, args.day, year
+        );
+        process::exit(1);
+    };
+
+    let input = match read_input(year, args.day, &args.source) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let options = Options {
+        part: args.part,
+        check_answers: matches!(args.source, Source::Puzzle),
+        bench: bench::budget(args.bench),
+    };
+    (solution.solve)(&input, &options);
+}
@@ -0,0 +1,114 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::{Answers, ANSWERS_FILE};
+use advent_of_code::config;
+use advent_of_code::days;
+use advent_of_code::history::{self, HISTORY_FILE};
+use advent_of_code::project_dir;
+use advent_of_code::stars::{self, MARKER, README_FILE};
+use advent_of_code::submit::{self, SUBMISSIONS_FILE};
+use std::fs;
+use std::process;
+
+struct Args {
+    year: Option<i32>,
+    all_years: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        all_years: args.contains("--all-years"),
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let config = match config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to load configuration: {}", e);
+            process::exit(1);
+        }
+    };
+    let years = if args.all_years {
+        days::years()
+    } else {
+        match args.year.map_or_else(|| config.year(), Ok) {
+            Ok(year) => vec![year],
+            Err(e) => {
+                eprintln!(
+                    "Failed to determine the year: {}, or pass `--year <year>`.",
+                    e
+                );
+                process::exit(1);
+            }
+        }
+    };
+
+    let answers = Answers::load(&project_dir().join(ANSWERS_FILE)).unwrap_or_else(|e| {
+        eprintln!("Failed to read \"{}\": {}", ANSWERS_FILE, e);
+        process::exit(1);
+    });
+    let attempts =
+        submit::read_attempts(&project_dir().join(SUBMISSIONS_FILE)).unwrap_or_else(|e| {
+            eprintln!("Failed to read \"{}\": {}", SUBMISSIONS_FILE, e);
+            process::exit(1);
+        });
+    let history = history::read_history(&project_dir().join(HISTORY_FILE)).unwrap_or_else(|e| {
+        eprintln!("Failed to read \"{}\": {}", HISTORY_FILE, e);
+        process::exit(1);
+    });
+
+    let mut sections = vec![];
+    let mut summary = vec![];
+    for &year in &years {
+        let registered: Vec<u8> = (1..=25).filter(|&d| days::get(year, d).is_some()).collect();
+        let progress = stars::progress(year, &registered, &answers, &attempts, &history);
+        // links are relative to the readme, with forward slashes on every platform.
+        let days_dir = config.days_dir(year);
+        let solutions = days_dir
+            .strip_prefix(project_dir())
+            .unwrap_or(&days_dir)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        sections.push(stars::render_section(year, &progress, &solutions));
+        let count: usize = progress.iter().map(|d| d.stars()).sum();
+        summary.push(format!("{} ⭐ in {}", count, year));
+    }
+
+    let readme_path = project_dir().join(README_FILE);
+    let readme = fs::read_to_string(&readme_path).unwrap_or_else(|e| {
+        eprintln!("Failed to read \"{}\": {}", README_FILE, e);
+        process::exit(1);
+    });
+    let Some(updated) = stars::update_readme(&readme, &sections) else {
+        eprintln!(
+            "Failed to update \"{}\": add a line `{}` where the table should go.",
+            README_FILE, MARKER
+        );
+        process::exit(1);
+    };
+    if let Err(e) = fs::write(&readme_path, updated) {
+        eprintln!("Failed to write \"{}\": {}", README_FILE, e);
+        process::exit(1);
+    }
+
+    println!(
+        "🎄 Updated \"{}\" with {}.",
+        README_FILE,
+        summary.join(", ")
+    );
+}
@@ -0,0 +1,181 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::{Answers, ANSWERS_FILE};
+use advent_of_code::aoc::Client;
+use advent_of_code::config;
+use advent_of_code::puzzle;
+use advent_of_code::runner::{self, Limits};
+use advent_of_code::submit::{self, Attempt, Outcome, SUBMISSIONS_FILE};
+use advent_of_code::{project_dir, ANSI_BOLD, ANSI_RESET};
+use std::{fs, process};
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: Option<i32>,
+    answer: Option<String>,
+}
+
+fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("expected 1 or 2, got \"{}\"", s)),
+    }
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        answer: args.opt_value_from_str("--answer")?,
+        day: args.free_from_str()?,
+        part: args.free_from_fn(parse_part)?,
+    })
+}
+
+/// Runs the release build of the solution and returns its answer for `part`.
+fn solve(year: i32, day: u8, part: u8) -> Option<String> {
+    let exe = match runner::build() {
+        Ok(exe) => exe,
+        Err(e) => {
+            eprintln!("Failed to build solutions: {}", e);
+            process::exit(1);
+        }
+    };
+    let limits = Limits {
+        timeout: Some(runner::DEFAULT_TIMEOUT),
+        ..Limits::default()
+    };
+
+    let run = runner::run_day(&exe, year, day, &[], &limits);
+    println!("{}", run.stdout.trim());
+    if run.outcome.is_failure() {
+        eprintln!("Day {:02} {}", day, run.outcome);
+    }
+    run.records
+        .into_iter()
+        .find(|r| r.part == part)
+        .and_then(|r| r.answer)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!("usage: `cargo submit <day> <part> [--year <year>] [--answer <answer>]`");
+            process::exit(1);
+        }
+    };
+
+    let config = match config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to load configuration: {}", e);
+            process::exit(1);
+        }
+    };
+    let year = match args.year.map_or_else(|| config.year(), Ok) {
+        Ok(year) => year,
+        Err(e) => {
+            eprintln!(
+                "Failed to determine the year: {}, or pass `--year <year>`.",
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    let Some(answer) = args.answer.or_else(|| solve(year, args.day, args.part)) else {
+        eprintln!("Part {} of day {} is not solved.", args.part, args.day);
+        process::exit(1);
+    };
+
+    let submissions_path = project_dir().join(SUBMISSIONS_FILE);
+    let attempts = match submit::read_attempts(&submissions_path) {
+        Ok(attempts) => attempts,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", SUBMISSIONS_FILE, e);
+            process::exit(1);
+        }
+    };
+
+    if let Err(refusal) = submit::check_attempt(&attempts, year, args.day, args.part, &answer) {
+        eprintln!("Not submitting \"{}\": {}.", answer, refusal);
+        process::exit(1);
+    }
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to create client: {}", e);
+            process::exit(1);
+        }
+    };
+
+    println!("---");
+    println!(
+        "Submitting \"{}\" for day {}, part {} ({})...",
+        answer, args.day, args.part, year
+    );
+
+    let outcome = match submit::submit(&client, year, args.day, args.part, &answer) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("Failed to submit answer: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let attempt = Attempt::new(year, args.day, args.part, &answer, outcome.clone());
+    if let Err(e) = submit::append_attempt(&submissions_path, &attempt) {
+        eprintln!("could not record submission: {}", e);
+    }
+
+    match &outcome {
+        Outcome::Correct => println!("⭐️ {}That's the right answer!{}", ANSI_BOLD, ANSI_RESET),
+        Outcome::Wait { .. } => println!(
+            "⏳ You submitted an answer too recently, {} before trying again.",
+            outcome
+        ),
+        outcome => println!("❌ {}{}{}", ANSI_BOLD, outcome, ANSI_RESET),
+    }
+
+    if outcome != Outcome::Correct {
+        process::exit(1);
+    }
+
+    let answers_path = project_dir().join(ANSWERS_FILE);
+    let locked = Answers::load(&answers_path).and_then(|mut answers| {
+        answers.set(year, args.day, args.part, answer);
+        answers.save(&answers_path)
+    });
+    match locked {
+        Ok(_) => println!("🎄 Accepted the answer into \"{}\".", ANSWERS_FILE),
+        Err(e) => eprintln!("could not update \"{}\": {}", ANSWERS_FILE, e),
+    }
+
+    // solving part one unlocks the description of part two.
+    if args.part == 1 {
+        let puzzle_path = puzzle::puzzle_path(year, args.day);
+        let client = client.refresh(true);
+        match puzzle::fetch(&client, year, args.day) {
+            Ok(markdown) if puzzle::has_part_two(&markdown) => {
+                let written = fs::create_dir_all(puzzle_path.parent().unwrap())
+                    .and_then(|_| fs::write(&puzzle_path, markdown));
+                match written {
+                    Ok(_) => println!(
+                        "🎄 Part two is unlocked, run `cargo read {}` to read it.",
+                        args.day
+                    ),
+                    Err(e) => eprintln!("could not write \"{}\": {}", puzzle_path.display(), e),
+                }
+            }
+            Ok(_) => (),
+            Err(e) => eprintln!("could not download puzzle description: {}", e),
+        }
+    }
+}
@@ -0,0 +1,180 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::aoc::Client;
+use advent_of_code::config;
+use advent_of_code::puzzle;
+use advent_of_code::runner;
+use advent_of_code::unlock::{self, Backoff, Clock, SystemClock};
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::{self, Command};
+use std::time::Duration;
+
+struct Args {
+    day: Option<u8>,
+    year: Option<i32>,
+}
+
+/// Puzzles unlock on the days 1 to 25 of December.
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err("expected a day from 1 to 25".to_string()),
+    }
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.opt_free_from_fn(parse_day)?,
+    })
+}
+
+fn on_retry(what: &str) -> impl Fn(u32, &advent_of_code::aoc::Error, Duration) + '_ {
+    move |attempt, e, delay| {
+        eprintln!(
+            "Attempt {} to download the {} failed: {}. Retrying in {:?}...",
+            attempt, what, e, delay
+        )
+    }
+}
+
+fn write(path: &Path, contents: &str) -> io::Result<()> {
+    fs::create_dir_all(path.parent().unwrap())?;
+    fs::write(path, contents)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!("usage: `cargo unlock [<day> [--year <year>]]`");
+            process::exit(1);
+        }
+    };
+
+    let config = match config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to load configuration: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let clock = SystemClock;
+    let (year, day, unlocks_at) = match args.day {
+        Some(day) => {
+            let year = match args.year.map_or_else(|| config.year(), Ok) {
+                Ok(year) => year,
+                Err(e) => {
+                    eprintln!(
+                        "Failed to determine the year: {}, or pass `--year <year>`.",
+                        e
+                    );
+                    process::exit(1);
+                }
+            };
+            (year, day, unlock::unlock_time(year, day))
+        }
+        None => unlock::next_unlock(clock.now()),
+    };
+
+    // fail early, not at unlock.
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to create client: {}", e);
+            process::exit(1);
+        }
+    };
+    let scaffold = match runner::build_bin("scaffold", false) {
+        Ok(exe) => exe,
+        Err(e) => {
+            eprintln!("Failed to build scaffold: {}", e);
+            process::exit(1);
+        }
+    };
+
+    println!(
+        "Day {} of {} unlocks at {}.",
+        day,
+        year,
+        unlocks_at.format("%Y-%m-%d %H:%M UTC")
+    );
+    unlock::wait_until(&clock, unlocks_at, |remaining| {
+        print!("\r⏳ {} ", unlock::format_countdown(remaining));
+        #[allow(unused_must_use)]
+        {
+            io::stdout().flush();
+        }
+    });
+    println!("\r🔓 Day {} is unlocked!", day);
+    println!("---");
+
+    // a day that is registered already has been scaffolded before.
+    if advent_of_code::days::get(year, day).is_none() {
+        let status = Command::new(&scaffold)
+            .arg(day.to_string())
+            .args(["--year", &year.to_string()])
+            .status();
+        match status {
+            Ok(status) if status.success() => (),
+            Ok(_) => eprintln!("Scaffolding failed, continuing with the download."),
+            Err(e) => eprintln!("could not run scaffold: {}", e),
+        }
+    }
+
+    let backoff = Backoff::default();
+    let input_path = advent_of_code::input_path("inputs", year, day);
+    let exists = fs::metadata(&input_path).is_ok_and(|m| m.len() > 0);
+    if !exists {
+        let input = unlock::retry(
+            &clock,
+            &backoff,
+            || client.input(year, day),
+            on_retry("input"),
+        );
+        match input.and_then(|input| write(&input_path, &input).map_err(Into::into)) {
+            Ok(_) => println!(
+                "🎄 Successfully wrote input to \"{}\".",
+                input_path.display()
+            ),
+            Err(e) => {
+                eprintln!("Failed to download input: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    let puzzle_path = puzzle::puzzle_path(year, day);
+    let markdown = unlock::retry(
+        &clock,
+        &backoff,
+        || puzzle::fetch(&client, year, day),
+        on_retry("puzzle description"),
+    );
+    match markdown.and_then(|md| write(&puzzle_path, &md).map(|_| md).map_err(Into::into)) {
+        Ok(markdown) => {
+            println!(
+                "🎄 Successfully wrote puzzle description to \"{}\".",
+                puzzle_path.display()
+            );
+            match puzzle::save_examples(year, day, &markdown) {
+                Ok(examples) => puzzle::print_examples(year, &examples),
+                Err(e) => eprintln!("could not extract examples: {}", e),
+            }
+        }
+        Err(e) => eprintln!("could not download puzzle description: {}", e),
+    }
+
+    println!("---");
+    println!(
+        "🎄 Type `cargo read {} --year {}` to read the puzzle and `cargo solve {} --year {}` to run your solution.",
+        day, year, day, year
+    );
+}
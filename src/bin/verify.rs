@@ -0,0 +1,182 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::{Answers, Verdict, ANSWERS_FILE};
+use advent_of_code::config;
+use advent_of_code::days;
+use advent_of_code::runner::{self, Limits};
+use advent_of_code::{ANSI_BOLD, ANSI_RESET};
+use std::process;
+use std::thread;
+
+struct Args {
+    accept: bool,
+    year: Option<i32>,
+    all_years: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        accept: args.contains("--accept"),
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        all_years: args.contains("--all-years"),
+    })
+}
+
+struct Row {
+    year: i32,
+    day: u8,
+    part: u8,
+    verdict: Verdict,
+    answer: Option<String>,
+    locked: Option<String>,
+}
+
+/// Prints one row per part. The year column is only shown if the rows span several years.
+fn print_table(rows: &[Row]) {
+    let answer_width = rows
+        .iter()
+        .filter_map(|r| r.answer.as_ref().map(|a| a.len()))
+        .chain([6])
+        .max()
+        .unwrap();
+    let show_year = rows.iter().any(|r| r.year != rows[0].year);
+    let year = |year: &str| {
+        if show_year {
+            format!("{:<5} ", year)
+        } else {
+            String::new()
+        }
+    };
+
+    println!(
+        "{}{}{:<4} {:<5} {:<8} {:<w$} Accepted{}",
+        ANSI_BOLD,
+        year("Year"),
+        "Day",
+        "Part",
+        "Status",
+        "Answer",
+        ANSI_RESET,
+        w = answer_width
+    );
+    for row in rows {
+        println!(
+            "{}{:<4} {:<5} {:<8} {:<w$} {}",
+            year(&row.year.to_string()),
+            format!("{:02}", row.day),
+            row.part,
+            row.verdict,
+            row.answer.as_deref().unwrap_or("-"),
+            row.locked.as_deref().unwrap_or("-"),
+            w = answer_width
+        );
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let answers_path = advent_of_code::project_dir().join(ANSWERS_FILE);
+    let mut answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", answers_path.display(), e);
+            process::exit(1);
+        }
+    };
+
+    let exe = match runner::build() {
+        Ok(exe) => exe,
+        Err(e) => {
+            eprintln!("Failed to build solutions: {}", e);
+            process::exit(1);
+        }
+    };
+    let jobs = thread::available_parallelism().map_or(1, |n| n.get());
+
+    let limits = Limits {
+        timeout: Some(runner::DEFAULT_TIMEOUT),
+        ..Limits::default()
+    };
+
+    let years = if args.all_years {
+        days::years()
+    } else {
+        let config = match config::load() {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Failed to load configuration: {}", e);
+                process::exit(1);
+            }
+        };
+        match args.year.map_or_else(|| config.year(), Ok) {
+            Ok(year) => vec![year],
+            Err(e) => {
+                eprintln!(
+                    "Failed to determine the year: {}, or pass `--year <year>`.",
+                    e
+                );
+                process::exit(1);
+            }
+        }
+    };
+
+    let days = runner::available_days(&years);
+    let mut rows = vec![];
+    for ((year, day), run) in runner::run_days(&exe, days, jobs, vec![], limits) {
+        if run.outcome.is_failure() {
+            eprintln!("{} day {:02} {}", year, day, run.outcome);
+        }
+        for part in [1, 2] {
+            let answer = run
+                .records
+                .iter()
+                .find(|r| r.part == part)
+                .and_then(|r| r.answer.clone());
+            let locked = answers.get(year, day, part).map(str::to_string);
+            rows.push(Row {
+                year,
+                day,
+                part,
+                verdict: Verdict::new(locked.as_deref(), answer.as_deref()),
+                answer,
+                locked,
+            });
+        }
+    }
+
+    print_table(&rows);
+
+    if args.accept {
+        let mut accepted = 0;
+        for row in &rows {
+            if let (Verdict::Missing, Some(answer)) = (&row.verdict, &row.answer) {
+                answers.set(row.year, row.day, row.part, answer.clone());
+                accepted += 1;
+            }
+        }
+        if let Err(e) = answers.save(&answers_path) {
+            eprintln!("Failed to write \"{}\": {}", answers_path.display(), e);
+            process::exit(1);
+        }
+        println!("---");
+        println!(
+            "🎄 Accepted {} new answers into \"{}\".",
+            accepted,
+            answers_path.display()
+        );
+    }
+
+    if !rows.iter().all(|r| r.verdict.is_ok()) {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,275 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::Deserialize;
+use std::env;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Project configuration, relative to the project root.
+pub const CONFIG_FILE: &str = "aoc.toml";
+/// Solution modules, relative to the project root.
+/// Not configurable: they are compiled as `crate::days`, so they have to stay in `src/days`.
+pub const DAYS_DIR: &str = "src/days";
+
+pub const YEAR_ENV: &str = "AOC_YEAR";
+pub const SESSION_FILE_ENV: &str = "AOC_SESSION_FILE";
+pub const LEADERBOARD_ENV: &str = "AOC_LEADERBOARD";
+pub const CONTACT_ENV: &str = "AOC_CONTACT";
+pub const INPUTS_ENV: &str = "AOC_INPUTS_DIR";
+pub const EXAMPLES_ENV: &str = "AOC_EXAMPLES_DIR";
+pub const PUZZLES_ENV: &str = "AOC_PUZZLES_DIR";
+pub const TEMPLATE_ENV: &str = "AOC_TEMPLATE";
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    /// An environment variable does not hold a valid value.
+    InvalidEnv {
+        name: &'static str,
+        value: String,
+    },
+    /// A required value is neither in the config file nor in the environment.
+    Missing {
+        key: &'static str,
+        env: &'static str,
+        hint: String,
+    },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "could not read \"{}\": {}", CONFIG_FILE, e),
+            Error::Parse(e) => write!(f, "invalid \"{}\": {}", CONFIG_FILE, e),
+            Error::InvalidEnv { name, value } => {
+                write!(f, "invalid value for {}: \"{}\"", name, value)
+            }
+            Error::Missing { key, env, hint } => write!(
+                f,
+                "`{}` is not configured. Add `{} = {}` to \"{}\" or set {}",
+                key, key, hint, CONFIG_FILE, env
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Paths {
+    pub inputs: PathBuf,
+    pub examples: PathBuf,
+    pub puzzles: PathBuf,
+    pub template: PathBuf,
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Paths {
+            inputs: PathBuf::from("src/inputs"),
+            examples: PathBuf::from("src/examples"),
+            puzzles: PathBuf::from("src/puzzles"),
+            template: PathBuf::from(crate::template::TEMPLATE_FILE),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Event year used when no `--year` is given.
+    pub year: Option<i32>,
+    /// File holding the session cookie, `~/.adventofcode.session` if not set.
+    pub session_file: Option<PathBuf>,
+    /// Id of the private leaderboard, shown in its url.
+    pub leaderboard: Option<u64>,
+    /// How Advent of Code can reach the user, sent with every request.
+    pub contact: Option<String>,
+    pub paths: Paths,
+}
+
+impl Config {
+    pub fn parse(contents: &str) -> Result<Self, Error> {
+        toml::from_str(contents).map_err(Error::Parse)
+    }
+
+    /// Overrides values with the environment variables returned by `var`.
+    pub fn apply_env(&mut self, var: impl Fn(&str) -> Option<String>) -> Result<(), Error> {
+        if let Some(value) = var(YEAR_ENV) {
+            let year = value.trim().parse().map_err(|_| Error::InvalidEnv {
+                name: YEAR_ENV,
+                value,
+            })?;
+            self.year = Some(year);
+        }
+        if let Some(value) = var(SESSION_FILE_ENV) {
+            self.session_file = Some(PathBuf::from(value));
+        }
+        if let Some(value) = var(LEADERBOARD_ENV) {
+            let id = value.trim().parse().map_err(|_| Error::InvalidEnv {
+                name: LEADERBOARD_ENV,
+                value,
+            })?;
+            self.leaderboard = Some(id);
+        }
+        if let Some(value) = var(CONTACT_ENV) {
+            self.contact = Some(value);
+        }
+        let paths = [
+            (INPUTS_ENV, &mut self.paths.inputs),
+            (EXAMPLES_ENV, &mut self.paths.examples),
+            (PUZZLES_ENV, &mut self.paths.puzzles),
+            (TEMPLATE_ENV, &mut self.paths.template),
+        ];
+        for (name, path) in paths {
+            if let Some(value) = var(name) {
+                *path = PathBuf::from(value);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads `aoc.toml` from `dir`, if it exists, and applies the environment.
+    pub fn load(dir: &Path) -> Result<Self, Error> {
+        let mut config = match fs::read_to_string(dir.join(CONFIG_FILE)) {
+            Ok(contents) => Config::parse(&contents)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Config::default(),
+            Err(e) => return Err(Error::Io(e)),
+        };
+        config.apply_env(|name| env::var(name).ok())?;
+        Ok(config)
+    }
+
+    pub fn year(&self) -> Result<i32, Error> {
+        self.year.ok_or_else(|| Error::Missing {
+            key: "year",
+            env: YEAR_ENV,
+            hint: crate::aoc::current_event_year(chrono::Utc::now()).to_string(),
+        })
+    }
+
+    pub fn leaderboard(&self) -> Result<u64, Error> {
+        self.leaderboard.ok_or_else(|| Error::Missing {
+            key: "leaderboard",
+            env: LEADERBOARD_ENV,
+            hint: "<id>".to_string(),
+        })
+    }
+
+    /// Session file, with a leading `~/` expanded to the home directory.
+    pub fn session_file(&self) -> Option<PathBuf> {
+        let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"));
+        match &self.session_file {
+            Some(path) => match (path.strip_prefix("~"), home) {
+                (Ok(rest), Some(home)) => Some(Path::new(&home).join(rest)),
+                _ => Some(path.clone()),
+            },
+            None => home.map(|home| Path::new(&home).join(crate::aoc::SESSION_FILE)),
+        }
+    }
+
+    /// Inputs of a year, e.g. `src/inputs/2022`.
+    pub fn inputs_dir(&self, year: i32) -> PathBuf {
+        crate::project_dir()
+            .join(&self.paths.inputs)
+            .join(year.to_string())
+    }
+
+    /// Examples and their manifest of a year, e.g. `src/examples/2022`.
+    pub fn examples_dir(&self, year: i32) -> PathBuf {
+        crate::project_dir()
+            .join(&self.paths.examples)
+            .join(year.to_string())
+    }
+
+    /// Puzzle descriptions of a year, e.g. `src/puzzles/2022`.
+    pub fn puzzles_dir(&self, year: i32) -> PathBuf {
+        crate::project_dir()
+            .join(&self.paths.puzzles)
+            .join(year.to_string())
+    }
+
+    /// Registry of all years, e.g. `src/days/mod.rs`.
+    pub fn years_registry(&self) -> PathBuf {
+        crate::project_dir().join(DAYS_DIR).join("mod.rs")
+    }
+
+    /// Solution modules of a year, e.g. `src/days/y2022`.
+    pub fn days_dir(&self, year: i32) -> PathBuf {
+        crate::project_dir()
+            .join(DAYS_DIR)
+            .join(format!("y{}", year))
+    }
+
+    pub fn template_path(&self) -> PathBuf {
+        crate::project_dir().join(&self.paths.template)
+    }
+}
+
+/// The project configuration, loaded once.
+///
+/// Panics if `aoc.toml` or the environment is invalid. Binaries call [`load`] first to report this properly.
+pub fn get() -> &'static Config {
+    load().unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn load() -> Result<&'static Config, Error> {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    if let Some(config) = CONFIG.get() {
+        return Ok(config);
+    }
+    let config = Config::load(crate::project_dir())?;
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse("year = 2022\n\n[paths]\ninputs = \"data/inputs\"\n").unwrap();
+        assert_eq!(config.year, Some(2022));
+        assert_eq!(config.paths.inputs, PathBuf::from("data/inputs"));
+        assert_eq!(config.paths.examples, PathBuf::from("src/examples"));
+        assert_eq!(config.session_file, None);
+
+        assert!(matches!(Config::parse("yaer = 2022"), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn test_apply_env() {
+        let mut config = Config::parse("year = 2022\nsession_file = \"~/aoc\"").unwrap();
+        config
+            .apply_env(|name| match name {
+                YEAR_ENV => Some("2021".to_string()),
+                LEADERBOARD_ENV => Some("123456".to_string()),
+                CONTACT_ENV => Some("me@example.com".to_string()),
+                PUZZLES_ENV => Some("puzzles".to_string()),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(config.year, Some(2021));
+        assert_eq!(config.paths.puzzles, PathBuf::from("puzzles"));
+        assert_eq!(config.session_file, Some(PathBuf::from("~/aoc")));
+        assert_eq!(config.leaderboard, Some(123456));
+        assert_eq!(config.contact.as_deref(), Some("me@example.com"));
+
+        let err = config
+            .apply_env(|name| (name == YEAR_ENV).then(|| "next".to_string()))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "invalid value for AOC_YEAR: \"next\"");
+    }
+
+    #[test]
+    fn test_missing_year() {
+        let err = Config::default().year().unwrap_err().to_string();
+        assert!(err.starts_with("`year` is not configured. Add `year = "));
+        assert!(err.ends_with("to \"aoc.toml\" or set AOC_YEAR"));
+    }
+}
@@ -0,0 +1,23 @@
+/*
+ * Registry of all years. `cargo scaffold` adds new years here.
+ */
+use crate::solution::{Day, Year};
+
+pub mod y2022;
+
+pub const YEARS: &[Year] = &[Year::new(2022, y2022::DAYS)];
+
+/// Years that have registered solutions, in ascending order.
+pub fn years() -> Vec<i32> {
+    let mut years: Vec<i32> = YEARS.iter().map(|y| y.year).collect();
+    years.sort_unstable();
+    years
+}
+
+pub fn get(year: i32, day: u8) -> Option<&'static Day> {
+    YEARS
+        .iter()
+        .filter(|y| y.year == year)
+        .flat_map(|y| y.days)
+        .find(|d| d.day == day)
+}
@@ -0,0 +1,44 @@
+use itertools::Itertools;
+
+use crate::parsing::ints;
+use crate::{Input, Solution};
+
+pub fn parse(input: &Input) -> Vec<u32> {
+    input
+        .paragraphs()
+        .map(|elf| ints::<u32>(elf).unwrap().iter().sum())
+        .collect()
+}
+
+pub fn part_one(elves: &[u32]) -> Option<u32> {
+    elves.iter().max().copied()
+}
+
+pub fn part_two(elves: &[u32]) -> Option<u32> {
+    Some(elves.iter().sorted().rev().take(3).sum::<u32>())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: i32 = 2022;
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<u32>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &Input) -> Self::Parsed {
+        parse(input)
+    }
+
+    fn part_one(elves: &Self::Parsed) -> Option<Self::AnswerOne> {
+        part_one(elves)
+    }
+
+    fn part_two(elves: &Self::Parsed) -> Option<Self::AnswerTwo> {
+        part_two(elves)
+    }
+}
+
+crate::example_tests!(Day01);
@@ -12,8 +12,11 @@ enum Outcome {
     Draw = 3,
 }
 
-use crate::Outcome::*;
-use crate::Throw::*;
+use crate::{Input, Solution};
+use Outcome::*;
+use Throw::*;
+
+type Round = (char, char);
 
 fn outcome(a: Throw, b: Throw) -> Outcome {
     match a {
@@ -59,20 +62,29 @@ fn outcome_choice(a: Throw, o: Outcome) -> Throw {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn parse(input: &str) -> Vec<Round> {
+    input
+        .lines()
+        .map(|line| {
+            let (a, b) = line.split_once(' ').unwrap();
+            (a.parse().unwrap(), b.parse().unwrap())
+        })
+        .collect()
+}
+
+pub fn part_one(rounds: &[Round]) -> Option<u32> {
     let mut score = 0;
-    for line in input.lines() {
-        let (a, b) = line.split_once(' ').unwrap();
+    for &(a, b) in rounds {
         let a = match a {
-            "A" => Rock,
-            "B" => Paper,
-            "C" => Scissors,
+            'A' => Rock,
+            'B' => Paper,
+            'C' => Scissors,
             _ => panic!(),
         };
         let b = match b {
-            "X" => Rock,
-            "Y" => Paper,
-            "Z" => Scissors,
+            'X' => Rock,
+            'Y' => Paper,
+            'Z' => Scissors,
             _ => panic!(),
         };
 
@@ -81,20 +93,19 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(score)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(rounds: &[Round]) -> Option<u32> {
     let mut score = 0;
-    for line in input.lines() {
-        let (a, o) = line.split_once(' ').unwrap();
+    for &(a, o) in rounds {
         let a = match a {
-            "A" => Rock,
-            "B" => Paper,
-            "C" => Scissors,
+            'A' => Rock,
+            'B' => Paper,
+            'C' => Scissors,
             _ => panic!(),
         };
         let o = match o {
-            "X" => AWin,
-            "Y" => Draw,
-            "Z" => BWin,
+            'X' => AWin,
+            'Y' => Draw,
+            'Z' => BWin,
             _ => panic!(),
         };
 
@@ -104,25 +115,27 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(score)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+pub struct Day02;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl Solution for Day02 {
+    const YEAR: i32 = 2022;
+    const DAY: u8 = 2;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_one(&input), Some(15));
+    type Parsed = Vec<Round>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &Input) -> Self::Parsed {
+        parse(input)
+    }
+
+    fn part_one(rounds: &Self::Parsed) -> Option<Self::AnswerOne> {
+        part_one(rounds)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_two(&input), Some(12));
+    fn part_two(rounds: &Self::Parsed) -> Option<Self::AnswerTwo> {
+        part_two(rounds)
     }
 }
+
+crate::example_tests!(Day02);
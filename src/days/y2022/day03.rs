@@ -1,6 +1,8 @@
 use itertools::Itertools;
 use std::collections::HashSet;
 
+use crate::{Input, Solution};
+
 fn priority(item: char) -> u32 {
     match item {
         'a'..='z' => 1 + item as u32 - 'a' as u32,
@@ -9,9 +11,13 @@ fn priority(item: char) -> u32 {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn parse(input: &Input) -> Vec<String> {
+    input.lines_typed().unwrap()
+}
+
+pub fn part_one(rucksacks: &[String]) -> Option<u32> {
     let mut total = 0;
-    for line in input.lines() {
+    for line in rucksacks {
         let (a, b) = line.split_at(line.len() / 2);
         let first: HashSet<_> = a.chars().collect();
         let second: HashSet<_> = b.chars().collect();
@@ -22,10 +28,10 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(total)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(rucksacks: &[String]) -> Option<u32> {
     Some(
-        input
-            .lines()
+        rucksacks
+            .iter()
             .map(|l| l.chars().collect::<HashSet<_>>())
             .chunks(3)
             .into_iter()
@@ -42,25 +48,27 @@ pub fn part_two(input: &str) -> Option<u32> {
     )
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+pub struct Day03;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl Solution for Day03 {
+    const YEAR: i32 = 2022;
+    const DAY: u8 = 3;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_one(&input), Some(157));
+    type Parsed = Vec<String>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &Input) -> Self::Parsed {
+        parse(input)
+    }
+
+    fn part_one(rucksacks: &Self::Parsed) -> Option<Self::AnswerOne> {
+        part_one(rucksacks)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_two(&input), Some(70));
+    fn part_two(rucksacks: &Self::Parsed) -> Option<Self::AnswerTwo> {
+        part_two(rucksacks)
     }
 }
+
+crate::example_tests!(Day03);
@@ -0,0 +1,63 @@
+use crate::parsing::parse_lines;
+use crate::{scan, FromInput, Input, Solution};
+
+#[derive(FromInput)]
+#[pattern("{start}-{end}")]
+pub struct Assignment {
+    start: u32,
+    end: u32,
+}
+
+impl Assignment {
+    fn contains(&self, other: &Self) -> bool {
+        other.start >= self.start && other.end <= self.end
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        other.start >= self.start && other.start <= self.end
+            || other.end >= self.start && other.end <= self.end
+            || other.start <= self.start && other.end >= self.end
+    }
+}
+
+pub fn parse(input: &str) -> Vec<(Assignment, Assignment)> {
+    parse_lines(input, |line| scan!("{},{}", line)).unwrap()
+}
+
+pub fn part_one(pairs: &[(Assignment, Assignment)]) -> Option<u32> {
+    Some(
+        pairs
+            .iter()
+            .filter(|(a, b)| a.contains(b) || b.contains(a))
+            .count() as u32,
+    )
+}
+
+pub fn part_two(pairs: &[(Assignment, Assignment)]) -> Option<u32> {
+    Some(pairs.iter().filter(|(a, b)| a.overlaps(b)).count() as u32)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: i32 = 2022;
+    const DAY: u8 = 4;
+
+    type Parsed = Vec<(Assignment, Assignment)>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &Input) -> Self::Parsed {
+        parse(input)
+    }
+
+    fn part_one(pairs: &Self::Parsed) -> Option<Self::AnswerOne> {
+        part_one(pairs)
+    }
+
+    fn part_two(pairs: &Self::Parsed) -> Option<Self::AnswerTwo> {
+        part_two(pairs)
+    }
+}
+
+crate::example_tests!(Day04);
@@ -0,0 +1,83 @@
+use itertools::Itertools;
+
+use crate::parsing::parse_lines;
+use crate::{scan, Input, Solution};
+
+type Stacks = Vec<Vec<char>>;
+type Move = (usize, usize, usize);
+type Puzzle = (Stacks, Vec<Move>);
+
+pub fn parse(input: &str) -> Puzzle {
+    let (diagram, instructions) = input.split_once("\n\n").unwrap();
+
+    let diagram_lines = diagram.lines().count();
+    let mut diagram = diagram.lines().rev();
+    let nums = diagram.next().unwrap();
+    let count = (nums.len() + 1) / 4;
+
+    let mut stacks = vec![vec![]; count];
+
+    for line in diagram {
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            let c = line.as_bytes()[1 + 4 * i] as char;
+            if c != ' ' {
+                stack.push(c)
+            }
+        }
+    }
+
+    let instructions = parse_lines(instructions, |line| {
+        let (count, src, dst): Move = scan!("move {} from {} to {}", line)?;
+        Ok((count, src - 1, dst - 1))
+    })
+    .map_err(|e| e.at(diagram_lines + 2))
+    .unwrap();
+
+    (stacks, instructions)
+}
+
+pub fn part_one((stacks, instructions): &Puzzle) -> Option<String> {
+    let mut stacks = stacks.clone();
+    for &(count, src, dst) in instructions {
+        for _ in 0..count {
+            let b = stacks[src].pop().unwrap();
+            stacks[dst].push(b);
+        }
+    }
+    Some(stacks.iter().map(|v| v.last().unwrap()).join(""))
+}
+
+pub fn part_two((stacks, instructions): &Puzzle) -> Option<String> {
+    let mut stacks = stacks.clone();
+    for &(count, src, dst) in instructions {
+        let i = stacks[src].len() - count;
+        let crates = stacks[src].drain(i..).collect_vec();
+        stacks[dst].extend(crates);
+    }
+    Some(stacks.iter().map(|v| v.last().unwrap()).join(""))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: i32 = 2022;
+    const DAY: u8 = 5;
+
+    type Parsed = Puzzle;
+    type AnswerOne = String;
+    type AnswerTwo = String;
+
+    fn parse(input: &Input) -> Self::Parsed {
+        parse(input)
+    }
+
+    fn part_one(puzzle: &Self::Parsed) -> Option<Self::AnswerOne> {
+        part_one(puzzle)
+    }
+
+    fn part_two(puzzle: &Self::Parsed) -> Option<Self::AnswerTwo> {
+        part_two(puzzle)
+    }
+}
+
+crate::example_tests!(Day05);
@@ -0,0 +1,47 @@
+use itertools::Itertools;
+
+use crate::{Input, Solution};
+
+fn find_marker(msg: &str, len: usize) -> Option<usize> {
+    msg.as_bytes()
+        .windows(len)
+        .position(|s| s.iter().all_unique())
+        .map(|r| r + len)
+}
+
+pub fn parse(input: &str) -> String {
+    input.to_string()
+}
+
+pub fn part_one(datastream: &str) -> Option<usize> {
+    find_marker(datastream, 4)
+}
+
+pub fn part_two(datastream: &str) -> Option<usize> {
+    find_marker(datastream, 14)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: i32 = 2022;
+    const DAY: u8 = 6;
+
+    type Parsed = String;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &Input) -> Self::Parsed {
+        parse(input)
+    }
+
+    fn part_one(datastream: &Self::Parsed) -> Option<Self::AnswerOne> {
+        part_one(datastream)
+    }
+
+    fn part_two(datastream: &Self::Parsed) -> Option<Self::AnswerTwo> {
+        part_two(datastream)
+    }
+}
+
+crate::example_tests!(Day06);
@@ -1,5 +1,7 @@
 use std::collections::BTreeMap;
 
+use crate::{Input, Solution};
+
 const FS_SIZE: u32 = 70_000_000;
 const NEEDED_SIZE: u32 = 30_000_000;
 
@@ -9,7 +11,10 @@ struct Directory {
 }
 
 impl Directory {
-    fn children<'a>(&'a self, tree: &'a DirectoryTree) -> impl Iterator<Item = (&String, &FsNode)> {
+    fn children<'a>(
+        &'a self,
+        tree: &'a DirectoryTree,
+    ) -> impl Iterator<Item = (&'a String, &'a FsNode)> {
         self.entries
             .iter()
             .map(|(name, idx)| (name, &tree.get(*idx).contents))
@@ -42,13 +47,12 @@ impl FsNode {
 
 #[derive(Debug)]
 struct DirEntry {
-    idx: usize,
     parent_idx: Option<usize>,
     contents: FsNode,
 }
 
 #[derive(Debug)]
-struct DirectoryTree {
+pub struct DirectoryTree {
     nodes: Vec<DirEntry>,
     root: usize,
 }
@@ -59,7 +63,6 @@ impl DirectoryTree {
         let root_dir = FsNode::Directory(Default::default());
         let idx = nodes.len();
         let root_entry = DirEntry {
-            idx,
             parent_idx: None,
             contents: root_dir,
         };
@@ -76,19 +79,22 @@ impl DirectoryTree {
     }
 
     fn get_root(&self) -> &Directory {
-        let FsNode::Directory(parent) = &self.nodes[self.root].contents else { panic!() };
+        let FsNode::Directory(parent) = &self.nodes[self.root].contents else {
+            panic!()
+        };
         parent
     }
 
     fn add_entry(&mut self, parent_idx: usize, name: String, entry: FsNode) -> usize {
         let idx = self.nodes.len();
         let child = DirEntry {
-            idx,
             parent_idx: Some(parent_idx),
             contents: entry,
         };
         self.nodes.push(child);
-        let FsNode::Directory(parent) = &mut self.get_mut(parent_idx).contents else { panic!() };
+        let FsNode::Directory(parent) = &mut self.get_mut(parent_idx).contents else {
+            panic!()
+        };
         parent.entries.insert(name, idx);
         idx
     }
@@ -111,7 +117,7 @@ impl DirectoryTree {
     }
 }
 
-fn parse(input: &str) -> DirectoryTree {
+pub fn parse(input: &str) -> DirectoryTree {
     let mut lines = input.lines();
     let mut tree = DirectoryTree::new();
     let mut curdir = tree.root;
@@ -146,12 +152,11 @@ fn parse(input: &str) -> DirectoryTree {
     tree
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let tree = parse(input);
+pub fn part_one(tree: &DirectoryTree) -> Option<u32> {
     Some(
         tree.dirs()
             .filter_map(|d| {
-                let size = d.get_size(&tree);
+                let size = d.get_size(tree);
                 if size <= 100_000 {
                     Some(size)
                 } else {
@@ -162,14 +167,13 @@ pub fn part_one(input: &str) -> Option<u32> {
     )
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let tree = parse(input);
-    let free = FS_SIZE - tree.get_root().get_size(&tree);
+pub fn part_two(tree: &DirectoryTree) -> Option<u32> {
+    let free = FS_SIZE - tree.get_root().get_size(tree);
     let needed = NEEDED_SIZE - free;
     Some(
         tree.dirs()
             .filter_map(|d| {
-                let size = d.get_size(&tree);
+                let size = d.get_size(tree);
                 if size >= needed {
                     Some(size)
                 } else {
@@ -181,25 +185,27 @@ pub fn part_two(input: &str) -> Option<u32> {
     )
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+pub struct Day07;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl Solution for Day07 {
+    const YEAR: i32 = 2022;
+    const DAY: u8 = 7;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_one(&input), Some(95437));
+    type Parsed = DirectoryTree;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &Input) -> Self::Parsed {
+        parse(input)
+    }
+
+    fn part_one(tree: &Self::Parsed) -> Option<Self::AnswerOne> {
+        part_one(tree)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_two(&input), Some(24933642));
+    fn part_two(tree: &Self::Parsed) -> Option<Self::AnswerTwo> {
+        part_two(tree)
     }
 }
+
+crate::example_tests!(Day07);
@@ -0,0 +1,82 @@
+use crate::helpers::{Direction, Grid};
+use crate::{Input, Solution};
+
+type Forest = Grid<i8>;
+
+pub fn parse(input: &Input) -> Forest {
+    input
+        .grid_with(|c| c.to_digit(10).map(|d| d as i8))
+        .unwrap()
+}
+
+fn is_visible(forest: &Forest, pos: (usize, usize), direction: Direction) -> bool {
+    let height = forest[pos];
+    forest
+        .ray(pos, direction.grid_offset())
+        .all(|tree| forest[tree] < height)
+}
+
+fn view_count(forest: &Forest, pos: (usize, usize), direction: Direction) -> usize {
+    let height = forest[pos];
+    let mut count = 0;
+    for tree in forest.ray(pos, direction.grid_offset()) {
+        count += 1;
+        if forest[tree] >= height {
+            break;
+        }
+    }
+    count
+}
+
+fn measure_score(forest: &Forest, pos: (usize, usize)) -> usize {
+    Direction::ALL
+        .iter()
+        .map(|&direction| view_count(forest, pos, direction))
+        .product()
+}
+
+#[allow(dead_code)]
+fn output(visible: &Grid<bool>) {
+    println!("{}", visible.map(|&tree| if tree { 'x' } else { '.' }));
+}
+
+pub fn part_one(forest: &Forest) -> Option<usize> {
+    let visible = Grid::from_fn(forest.width(), forest.height(), |pos| {
+        Direction::ALL
+            .iter()
+            .any(|&direction| is_visible(forest, pos, direction))
+    });
+    Some(visible.iter().filter(|&e| *e).count())
+}
+
+pub fn part_two(forest: &Forest) -> Option<usize> {
+    forest
+        .positions()
+        .map(|pos| measure_score(forest, pos))
+        .max()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: i32 = 2022;
+    const DAY: u8 = 8;
+
+    type Parsed = Forest;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &Input) -> Self::Parsed {
+        parse(input)
+    }
+
+    fn part_one(forest: &Self::Parsed) -> Option<Self::AnswerOne> {
+        part_one(forest)
+    }
+
+    fn part_two(forest: &Self::Parsed) -> Option<Self::AnswerTwo> {
+        part_two(forest)
+    }
+}
+
+crate::example_tests!(Day08);
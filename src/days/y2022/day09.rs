@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+
+use crate::helpers::{Direction, Point2};
+use crate::{Input, Solution};
+
+type Motion = (Direction, u32);
+
+pub fn parse(input: &str) -> Vec<Motion> {
+    input
+        .lines()
+        .map(|l| {
+            let (dir, count) = l.split_once(' ').unwrap();
+            (dir.parse().unwrap(), count.parse().unwrap())
+        })
+        .collect()
+}
+
+fn simulate(motions: &[Motion], count: usize) -> usize {
+    let mut rope: Vec<Point2> = vec![Point2::ORIGIN; count];
+    let mut positions: HashSet<Point2> = HashSet::new();
+    positions.insert(*rope.last().unwrap());
+    for &(dir, count) in motions {
+        for _ in 0..count {
+            rope[0] += dir;
+            let mut previous = rope[0];
+            for knot in rope.iter_mut().skip(1) {
+                if knot.chebyshev(previous) > 1 {
+                    *knot += (previous - *knot).signum();
+                    previous = *knot;
+                } else {
+                    break;
+                }
+            }
+            positions.insert(*rope.last().unwrap());
+        }
+    }
+    positions.len()
+}
+
+pub fn part_one(motions: &[Motion]) -> Option<usize> {
+    Some(simulate(motions, 2))
+}
+
+pub fn part_two(motions: &[Motion]) -> Option<usize> {
+    Some(simulate(motions, 10))
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: i32 = 2022;
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<Motion>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &Input) -> Self::Parsed {
+        parse(input)
+    }
+
+    fn part_one(motions: &Self::Parsed) -> Option<Self::AnswerOne> {
+        part_one(motions)
+    }
+
+    fn part_two(motions: &Self::Parsed) -> Option<Self::AnswerTwo> {
+        part_two(motions)
+    }
+}
+
+crate::example_tests!(Day09);
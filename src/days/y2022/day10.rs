@@ -1,23 +1,17 @@
-use itertools::Itertools;
+use crate::helpers::Grid;
+use crate::parsing::parse_lines;
+use crate::{FromInput, Input, Solution};
 
-enum Opcode {
+#[derive(FromInput)]
+pub enum Opcode {
+    #[pattern("noop")]
     Noop,
+    #[pattern("addx {}")]
     Addx(i32),
 }
 
-fn parse(input: &str) -> Vec<Opcode> {
-    let mut prog = vec![];
-    for line in input.lines() {
-        let mut tokens = line.split(' ');
-        let mnem = tokens.next().unwrap();
-        let insn = match mnem {
-            "noop" => Opcode::Noop,
-            "addx" => Opcode::Addx(tokens.next().unwrap().parse().unwrap()),
-            _ => panic!("Unexpected instruction"),
-        };
-        prog.push(insn);
-    }
-    prog
+pub fn parse(input: &str) -> Vec<Opcode> {
+    parse_lines(input, str::parse).unwrap()
 }
 
 struct Vm<'a> {
@@ -30,7 +24,7 @@ struct Vm<'a> {
 }
 
 impl Vm<'_> {
-    fn new(prog: &[Opcode]) -> Vm {
+    fn new(prog: &[Opcode]) -> Vm<'_> {
         Vm {
             prog,
             cycle: 0,
@@ -85,9 +79,8 @@ impl Vm<'_> {
     }
 }
 
-pub fn part_one(input: &str) -> Option<i32> {
-    let prog = parse(input);
-    let mut vm = Vm::new(&prog);
+pub fn part_one(prog: &[Opcode]) -> Option<i32> {
+    let mut vm = Vm::new(prog);
     let mut result = 0;
     let cycles = vec![20, 60, 100, 140, 180, 220];
     let mut elapsed = 0;
@@ -97,60 +90,57 @@ pub fn part_one(input: &str) -> Option<i32> {
             elapsed += 1;
         }
         let strength = (elapsed + 1) * vm.x;
-        let pc = vm.pc;
-        println!("{elapsed} ({pc}): {elapsed} * {} = {strength}", &vm.x);
         result += strength;
     }
     Some(result)
 }
 
 const CRT_WIDTH: usize = 40;
+const CRT_HEIGHT: usize = 6;
 
-fn display(crt: &[bool]) {
-    for line in &crt.iter().chunks(CRT_WIDTH) {
-        for pixel in line {
-            print!("{}", if *pixel { '#' } else { '.' });
-        }
-        println!();
-    }
+fn render(crt: &Grid<bool>) -> String {
+    crt.map(|&pixel| if pixel { '#' } else { '.' }).to_string()
 }
 
-pub fn part_two(input: &str) -> Option<bool> {
-    let prog = parse(input);
-    let mut vm = Vm::new(&prog);
-    let mut crt: Vec<bool> = vec![false; 240];
+/// The picture on the CRT. Read the letters off it to get the answer.
+pub fn part_two(prog: &[Opcode]) -> Option<String> {
+    let mut vm = Vm::new(prog);
+    let mut crt = Grid::filled(CRT_WIDTH, CRT_HEIGHT, false);
     for i in 0.. {
-        if vm.x.abs_diff(i % CRT_WIDTH as i32) <= 1 {
-            crt[i as usize] = true;
+        let pos = (i / CRT_WIDTH, i % CRT_WIDTH);
+        if vm.x.abs_diff(pos.1 as i32) <= 1 {
+            if let Some(pixel) = crt.get_mut(pos) {
+                *pixel = true;
+            }
         }
         if !vm.tick() {
             break;
         }
     }
-    println!();
-    display(&crt);
-    Some(true)
+    Some(render(&crt))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: i32 = 2022;
+    const DAY: u8 = 10;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    type Parsed = Vec<Opcode>;
+    type AnswerOne = i32;
+    type AnswerTwo = String;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_one(&input), Some(13140));
+    fn parse(input: &Input) -> Self::Parsed {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_two(&input), Some(true));
+    fn part_one(prog: &Self::Parsed) -> Option<Self::AnswerOne> {
+        part_one(prog)
+    }
+
+    fn part_two(prog: &Self::Parsed) -> Option<Self::AnswerTwo> {
+        part_two(prog)
     }
 }
+
+crate::example_tests!(Day10);
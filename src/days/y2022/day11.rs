@@ -0,0 +1,134 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+use crate::parsing::{ints, parse_paragraphs, KeyValues, ParseError};
+use crate::{Input, Solution};
+
+#[derive(Clone, Debug)]
+enum Operation {
+    Plus,
+    Times,
+}
+
+#[derive(Clone, Debug)]
+enum Operand {
+    Number(u32),
+    Self_,
+}
+
+impl FromStr for Operation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Operation::Plus),
+            "*" => Ok(Operation::Times),
+            _ => Err(format!("unknown operation {:?}", s)),
+        }
+    }
+}
+
+impl FromStr for Operand {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "old" => Ok(Operand::Self_),
+            n => n.parse().map(Operand::Number),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    items: VecDeque<u32>,
+    operation: Operation,
+    operand: Operand,
+    test: u32,
+    if_true: usize,
+    if_false: usize,
+}
+
+fn parse_monkey(block: &str) -> Result<Monkey, ParseError> {
+    let block = KeyValues::parse(block)?;
+    let (operation, operand) = block.scan("Operation", "new = old {} {}")?;
+    let (test,) = block.scan("Test", "divisible by {}")?;
+    let (if_true,) = block.scan("If true", "throw to monkey {}")?;
+    let (if_false,) = block.scan("If false", "throw to monkey {}")?;
+    Ok(Monkey {
+        items: block.get_with("Starting items", ints)?.into(),
+        operation,
+        operand,
+        test,
+        if_true,
+        if_false,
+    })
+}
+
+pub fn parse(input: &str) -> Vec<Monkey> {
+    parse_paragraphs(input, parse_monkey).unwrap()
+}
+
+pub fn part_one(monkeys: &[Monkey]) -> Option<u32> {
+    let mut monkeys = monkeys.to_vec();
+    let mut counts = vec![0; monkeys.len()];
+    for _ in 0..20 {
+        for i in 0..monkeys.len() {
+            let m = &mut monkeys[i];
+            let mut passed = Vec::with_capacity(m.items.len());
+            for mut worry in m.items.drain(0..) {
+                counts[i] += 1;
+                let operand = match m.operand {
+                    Operand::Number(n) => n,
+                    Operand::Self_ => worry,
+                };
+                worry = match m.operation {
+                    Operation::Plus => worry + operand,
+                    Operation::Times => worry * operand,
+                };
+                worry /= 3;
+                let next = if worry % m.test == 0 {
+                    m.if_true
+                } else {
+                    m.if_false
+                };
+                passed.push((next, worry));
+            }
+            for (next, worry) in passed {
+                monkeys[next].items.push_back(worry);
+            }
+        }
+    }
+    Some(counts.iter().sorted().rev().take(2).product())
+}
+
+pub fn part_two(_monkeys: &[Monkey]) -> Option<u32> {
+    None
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: i32 = 2022;
+    const DAY: u8 = 11;
+
+    type Parsed = Vec<Monkey>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &Input) -> Self::Parsed {
+        parse(input)
+    }
+
+    fn part_one(monkeys: &Self::Parsed) -> Option<Self::AnswerOne> {
+        part_one(monkeys)
+    }
+
+    fn part_two(monkeys: &Self::Parsed) -> Option<Self::AnswerTwo> {
+        part_two(monkeys)
+    }
+}
+
+crate::example_tests!(Day11);
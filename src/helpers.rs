@@ -2,3 +2,821 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// Offsets of the four orthogonal neighbours as `(row, col)`: up, right, down, left, like [`Direction::ALL`].
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets of all eight neighbours as `(row, col)`, clockwise from up, like [`Direction8::ALL`].
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseGridError {
+    Empty,
+    /// A line is not as long as the first one.
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A character could not be converted into a cell.
+    InvalidChar {
+        line: usize,
+        column: usize,
+        c: char,
+    },
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "the grid is empty"),
+            ParseGridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: expected {} characters, found {}",
+                line, expected, found
+            ),
+            ParseGridError::InvalidChar { line, column, c } => {
+                write!(
+                    f,
+                    "line {}, column {}: invalid character {:?}",
+                    line, column, c
+                )
+            }
+        }
+    }
+}
+
+/// A rectangular grid, stored row by row. Positions are `(row, col)`, starting at the top left.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells, row by row. Panics if there are not `width * height` cells.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Creates a grid by calling `f` with every position.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(&mut f)
+            .collect();
+        Grid::new(width, height, cells)
+    }
+
+    /// Parses one cell per character, one row per line. Trailing empty lines are ignored.
+    pub fn parse_with(
+        input: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseGridError> {
+        let lines: Vec<&str> = input.trim_end_matches(['\r', '\n']).lines().collect();
+        let width = lines.first().map_or(0, |l| l.chars().count());
+        if width == 0 {
+            return Err(ParseGridError::Empty);
+        }
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (i, line) in lines.iter().enumerate() {
+            let found = line.chars().count();
+            if found != width {
+                return Err(ParseGridError::Ragged {
+                    line: i + 1,
+                    expected: width,
+                    found,
+                });
+            }
+            for (j, c) in line.chars().enumerate() {
+                let cell = f(c).ok_or(ParseGridError::InvalidChar {
+                    line: i + 1,
+                    column: j + 1,
+                    c,
+                })?;
+                cells.push(cell);
+            }
+        }
+        Ok(Grid::new(width, lines.len(), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, col): (usize, usize)) -> Option<&T> {
+        (row < self.height && col < self.width).then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, (row, col): (usize, usize)) -> Option<&mut T> {
+        (row < self.height && col < self.width).then(|| &mut self.cells[row * self.width + col])
+    }
+
+    /// The cell at a position that may lie outside of the grid, which wraps around at the edges.
+    pub fn get_wrapping(&self, (row, col): (isize, isize)) -> &T {
+        let row = row.rem_euclid(self.height as isize) as usize;
+        let col = col.rem_euclid(self.width as isize) as usize;
+        &self[(row, col)]
+    }
+
+    pub fn set(&mut self, pos: (usize, usize), value: T) {
+        self[pos] = value;
+    }
+
+    /// The position `offset` away from `pos`, if it lies in the grid.
+    pub fn offset(
+        &self,
+        (row, col): (usize, usize),
+        (dr, dc): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(dr).filter(|&r| r < self.height)?;
+        let col = col.checked_add_signed(dc).filter(|&c| c < self.width)?;
+        Some((row, col))
+    }
+
+    /// Positions of all cells, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Orthogonal neighbours of `pos` that lie in the grid.
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS_4
+            .into_iter()
+            .filter_map(move |d| self.offset(pos, d))
+    }
+
+    /// Orthogonal and diagonal neighbours of `pos` that lie in the grid.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |d| self.offset(pos, d))
+    }
+
+    /// Positions from `pos` in steps of `direction` up to the edge, without `pos` itself.
+    pub fn ray(
+        &self,
+        pos: (usize, usize),
+        direction: (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        std::iter::successors(self.offset(pos, direction), move |&p| {
+            self.offset(p, direction)
+        })
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(col < self.width, "column {} out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // `chunks` needs a non-zero size, even for a grid without cells.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Mirrors the grid along its main diagonal.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(row, col)| {
+            self[(col, row)].clone()
+        })
+    }
+
+    /// Rotates the grid by 90° clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(row, col)| {
+            self[(self.height - 1 - col, row)].clone()
+        })
+    }
+
+    /// Rotates the grid by 90° counterclockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(row, col)| {
+            self[(col, self.width - 1 - row)].clone()
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "position {:?} out of bounds of a {}x{} grid",
+                pos, width, height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos).unwrap_or_else(|| {
+            panic!(
+                "position {:?} out of bounds of a {}x{} grid",
+                pos, width, height
+            )
+        })
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, Some)
+    }
+}
+
+/// An empty grid, with no rows and no columns.
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Grid::new(0, 0, vec![])
+    }
+}
+
+/// Renders one line per row, without separators between the cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A point on a plane. `x` grows to the right and `y` grows downwards, like the rows of a [`Grid`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Distance if diagonal steps are allowed, like a king on a chessboard.
+    pub fn chebyshev(self, other: Point2) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The sign of each coordinate, i.e. a step of at most one towards the point.
+    pub fn signum(self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn neighbours4(self) -> [Point2; 4] {
+        Direction::ALL.map(|d| self + d)
+    }
+
+    pub fn neighbours8(self) -> [Point2; 8] {
+        Direction8::ALL.map(|d| self + d)
+    }
+
+    /// The grid position `(row, col)` of the point, if it has no negative coordinates.
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        Some((self.y.try_into().ok()?, self.x.try_into().ok()?))
+    }
+
+    pub fn from_index((row, col): (usize, usize)) -> Self {
+        Point2::new(col as i64, row as i64)
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// A point in space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Point3) -> i64 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    pub fn signum(self) -> Point3 {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// The six points that share a face with this one.
+    pub fn neighbours6(self) -> [Point3; 6] {
+        [
+            Point3::new(1, 0, 0),
+            Point3::new(-1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 0, 1),
+            Point3::new(0, 0, -1),
+        ]
+        .map(|d| self + d)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// Implements the arithmetic operators of a point type for the given coordinates.
+macro_rules! point_ops {
+    ($point:ident, $($c:ident),+) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($c: self.$c + other.$c),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($c: self.$c - other.$c),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, factor: i64) -> $point {
+                $point { $($c: self.$c * factor),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($c: -self.$c),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+point_ops!(Point2, x, y);
+point_ops!(Point3, x, y, z);
+
+/// One of the four orthogonal directions, in clockwise order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// One step in this direction.
+    pub fn delta(self) -> Point2 {
+        let (row, col) = self.grid_offset();
+        Point2::new(col as i64, row as i64)
+    }
+
+    /// One step in this direction as a `(row, col)` offset of a [`Grid`].
+    pub fn grid_offset(self) -> (isize, isize) {
+        NEIGHBOURS_4[self.index()]
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self.index() + 2) % 4]
+    }
+}
+
+/// Parses `U`, `R`, `D` and `L` as well as the compass points `N`, `E`, `S` and `W`, with north being up.
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" | "N" => Ok(Direction::Up),
+            "R" | "E" => Ok(Direction::Right),
+            "D" | "S" => Ok(Direction::Down),
+            "L" | "W" => Ok(Direction::Left),
+            _ => Err(format!("invalid direction \"{}\"", s)),
+        }
+    }
+}
+
+impl Add<Direction> for Point2 {
+    type Output = Point2;
+
+    fn add(self, direction: Direction) -> Point2 {
+        self + direction.delta()
+    }
+}
+
+impl AddAssign<Direction> for Point2 {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+/// One of the eight compass directions, in clockwise order starting north, i.e. up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn rotate(self, eighths: usize) -> Direction8 {
+        Direction8::ALL[(self.index() + eighths) % 8]
+    }
+
+    pub fn delta(self) -> Point2 {
+        let (row, col) = self.grid_offset();
+        Point2::new(col as i64, row as i64)
+    }
+
+    pub fn grid_offset(self) -> (isize, isize) {
+        NEIGHBOURS_8[self.index()]
+    }
+
+    /// Rotates by 45° clockwise.
+    pub fn rotate_cw(self) -> Direction8 {
+        self.rotate(1)
+    }
+
+    /// Rotates by 45° counterclockwise.
+    pub fn rotate_ccw(self) -> Direction8 {
+        self.rotate(7)
+    }
+
+    pub fn turn_right(self) -> Direction8 {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Direction8 {
+        self.rotate(6)
+    }
+
+    pub fn reverse(self) -> Direction8 {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Direction8::ALL[direction.index() * 2]
+    }
+}
+
+/// Parses the compass points `N`, `NE`, ..., `NW` as well as `U`, `R`, `D` and `L`.
+impl FromStr for Direction8 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Direction8::NE),
+            "SE" => Ok(Direction8::SE),
+            "SW" => Ok(Direction8::SW),
+            "NW" => Ok(Direction8::NW),
+            _ => s.parse::<Direction>().map(Direction8::from),
+        }
+    }
+}
+
+impl Add<Direction8> for Point2 {
+    type Output = Point2;
+
+    fn add(self, direction: Direction8) -> Point2 {
+        self + direction.delta()
+    }
+}
+
+impl AddAssign<Direction8> for Point2 {
+    fn add_assign(&mut self, direction: Direction8) {
+        *self = *self + direction;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef\n".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert_eq!(
+            "ab\r\ncd\r\n".parse::<Grid<char>>().unwrap().to_string(),
+            "ab\ncd"
+        );
+
+        let digits = Grid::parse_with("12\n34", |c| c.to_digit(10)).unwrap();
+        assert_eq!(digits.iter().sum::<u32>(), 10);
+
+        assert_eq!("".parse::<Grid<char>>(), Err(ParseGridError::Empty));
+        assert_eq!(Grid::<char>::default().to_string(), "");
+        assert_eq!(
+            "abc\nde\n".parse::<Grid<char>>(),
+            Err(ParseGridError::Ragged {
+                line: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+        let err = Grid::parse_with("12\n3x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 2: invalid character 'x'");
+    }
+
+    #[test]
+    fn test_indexing() {
+        let mut grid = grid();
+        assert_eq!(grid.get((0, 2)), Some(&'c'));
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get_wrapping((-1, 3)), &'d');
+        assert_eq!(grid.get_wrapping((5, -4)), &'f');
+        assert_eq!(grid.offset((0, 0), (1, 1)), Some((1, 1)));
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+
+        grid.set((1, 1), 'x');
+        *grid.get_mut((0, 0)).unwrap() = 'y';
+        assert_eq!(grid.to_string(), "ybc\ndxf");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::filled(3, 3, 0);
+        assert_eq!(
+            grid.neighbours4((1, 1)).collect::<Vec<_>>(),
+            [(0, 1), (1, 2), (2, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 1), (1, 0)]
+        );
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid = Grid::from_fn(3, 2, |(row, col)| row * 3 + col);
+        assert_eq!(grid.row(1), [3, 4, 5]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [1, 4]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(
+            grid.columns().map(|c| c.sum::<usize>()).collect::<Vec<_>>(),
+            [3, 5, 7]
+        );
+        assert_eq!(
+            grid.ray((1, 0), (0, 1)).collect::<Vec<_>>(),
+            [(1, 1), (1, 2)]
+        );
+        assert_eq!(grid.ray((1, 0), (-1, -1)).count(), 0);
+        assert_eq!(
+            grid.positions().map(|p| grid[p]).collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+
+    #[test]
+    fn test_points() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(4, 2);
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(b - a, Point2::new(3, 4));
+        assert_eq!(-a * 2, Point2::new(-2, 4));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((b - a).signum(), Point2::new(1, 1));
+        assert_eq!(a.to_string(), "(1, -2)");
+
+        let mut c = Point3::new(1, 2, 3);
+        c += Point3::new(1, 1, 1);
+        c -= Point3::ORIGIN;
+        assert_eq!(c, Point3::new(2, 3, 4));
+        assert_eq!(c.manhattan(Point3::ORIGIN), 9);
+        assert_eq!(c.chebyshev(Point3::ORIGIN), 4);
+        assert_eq!(c.neighbours6().len(), 6);
+        assert!(c.neighbours6().iter().all(|n| n.manhattan(c) == 1));
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        assert_eq!(Point2::ORIGIN + Direction::Up, Point2::new(0, -1));
+        assert_eq!("U".parse(), Ok(Direction::Up));
+        assert_eq!("W".parse(), Ok(Direction::Left));
+        assert!("X".parse::<Direction>().is_err());
+
+        assert_eq!(Direction8::NW.rotate_cw(), Direction8::N);
+        assert_eq!(Direction8::N.rotate_ccw(), Direction8::NW);
+        assert_eq!(Direction8::NE.turn_right(), Direction8::SE);
+        assert_eq!(Direction8::SW.reverse(), Direction8::NE);
+        assert!(Direction8::SE.is_diagonal());
+        assert_eq!(Direction8::from(Direction::Down), Direction8::S);
+        assert_eq!(Direction8::SE.delta(), Point2::new(1, 1));
+        assert_eq!("NE".parse(), Ok(Direction8::NE));
+        assert_eq!("R".parse(), Ok(Direction8::E));
+
+        let p = Point2::new(2, 3);
+        assert_eq!(
+            p.neighbours4().map(|n| n - p),
+            Direction::ALL.map(Direction::delta)
+        );
+        assert_eq!(p.neighbours8()[1], Point2::new(3, 2));
+    }
+
+    #[test]
+    fn test_grid_index() {
+        let grid = Grid::from_fn(3, 2, |(row, col)| row * 3 + col);
+        let p = Point2::new(2, 1);
+        assert_eq!(p.to_index(), Some((1, 2)));
+        assert_eq!(grid[p.to_index().unwrap()], 5);
+        assert_eq!(Point2::from_index((1, 2)), p);
+        assert_eq!(Point2::new(-1, 0).to_index(), None);
+        assert_eq!(
+            grid.offset((1, 1), Direction::Up.grid_offset()),
+            Some((0, 1))
+        );
+        assert_eq!(
+            grid.ray((0, 0), Direction8::SE.grid_offset())
+                .collect::<Vec<_>>(),
+            [(1, 1)]
+        );
+    }
+}
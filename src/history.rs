@@ -0,0 +1,191 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+
+pub const HISTORY_FILE: &str = "timing_history.jsonl";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    /// Runs recorded before years were tracked have year `0`, so they are never used as baselines.
+    #[serde(default)]
+    pub year: i32,
+    pub day: u8,
+    pub part: u8,
+    pub duration_ns: u64,
+}
+
+/// Timings of a single `cargo all` run.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Run {
+    pub commit: Option<String>,
+    pub date: String,
+    pub bench: bool,
+    pub timings: Vec<Timing>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Regression {
+    pub year: i32,
+    pub day: u8,
+    pub part: u8,
+    pub baseline_ns: u64,
+    pub duration_ns: u64,
+}
+
+impl Regression {
+    /// Slowdown relative to the baseline in percent.
+    /// A baseline of 0 ns is below the resolution of the timer and never counts as a slowdown.
+    pub fn slowdown(&self) -> f64 {
+        if self.baseline_ns == 0 {
+            return 0.0;
+        }
+        (self.duration_ns as f64 / self.baseline_ns as f64 - 1.0) * 100.0
+    }
+}
+
+impl Run {
+    pub fn new(bench: bool, timings: Vec<Timing>) -> Self {
+        Run {
+            commit: current_commit(),
+            date: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            bench,
+            timings,
+        }
+    }
+}
+
+/// Short hash of `HEAD`, suffixed with `-dirty` if the working tree has changes.
+pub fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())?;
+    let hash = String::from_utf8(output.stdout).ok()?.trim().to_string();
+
+    let dirty = Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .is_ok_and(|o| !o.stdout.is_empty());
+
+    Some(if dirty { hash + "-dirty" } else { hash })
+}
+
+pub fn read_history(path: &Path) -> io::Result<Vec<Run>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+    contents
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| serde_json::from_str(l).map_err(io::Error::from))
+        .collect()
+}
+
+pub fn append_run(path: &Path, run: &Run) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let line = serde_json::to_string(run).map_err(io::Error::from)?;
+    writeln!(file, "{}", line)
+}
+
+/// Median of all recorded timings for a part, considering only runs made in the same mode.
+pub fn baseline(history: &[Run], year: i32, day: u8, part: u8, bench: bool) -> Option<u64> {
+    let mut samples: Vec<u64> = history
+        .iter()
+        .filter(|r| r.bench == bench)
+        .flat_map(|r| &r.timings)
+        .filter(|t| t.year == year && t.day == day && t.part == part)
+        .map(|t| t.duration_ns)
+        .collect();
+    if samples.is_empty() {
+        return None;
+    }
+    samples.sort_unstable();
+    Some(samples[samples.len() / 2])
+}
+
+/// Parts of `run` that are slower than their baseline by more than `threshold` percent.
+pub fn find_regressions(history: &[Run], run: &Run, threshold: f64) -> Vec<Regression> {
+    run.timings
+        .iter()
+        .filter_map(|t| {
+            let regression = Regression {
+                year: t.year,
+                day: t.day,
+                part: t.part,
+                baseline_ns: baseline(history, t.year, t.day, t.part, run.bench)?,
+                duration_ns: t.duration_ns,
+            };
+            (regression.slowdown() > threshold).then_some(regression)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(bench: bool, timings: &[(u8, u8, u64)]) -> Run {
+        Run {
+            commit: None,
+            date: "2022-12-07T05:00:00Z".into(),
+            bench,
+            timings: timings
+                .iter()
+                .map(|&(day, part, duration_ns)| Timing {
+                    year: 2022,
+                    day,
+                    part,
+                    duration_ns,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_baseline() {
+        let history = vec![
+            run(false, &[(7, 1, 100), (7, 2, 50)]),
+            run(false, &[(7, 1, 300)]),
+            run(false, &[(7, 1, 200)]),
+            run(true, &[(7, 1, 10)]),
+        ];
+        assert_eq!(baseline(&history, 2022, 7, 1, false), Some(200));
+        assert_eq!(baseline(&history, 2022, 7, 1, true), Some(10));
+        assert_eq!(baseline(&history, 2022, 7, 2, true), None);
+        assert_eq!(baseline(&history, 2022, 8, 1, false), None);
+        assert_eq!(baseline(&history, 2021, 7, 1, false), None);
+    }
+
+    #[test]
+    fn test_find_regressions() {
+        let history = vec![run(false, &[(7, 1, 100), (7, 2, 100)])];
+        let current = run(false, &[(7, 1, 115), (7, 2, 130), (8, 1, 1000)]);
+        let regressions = find_regressions(&history, &current, 20.0);
+        assert_eq!(
+            regressions,
+            vec![Regression {
+                year: 2022,
+                day: 7,
+                part: 2,
+                baseline_ns: 100,
+                duration_ns: 130,
+            }]
+        );
+        assert!((regressions[0].slowdown() - 30.0).abs() < 1.0e-6);
+
+        let unmeasured = Regression {
+            baseline_ns: 0,
+            ..regressions[0].clone()
+        };
+        assert_eq!(unmeasured.slowdown(), 0.0);
+    }
+}
@@ -0,0 +1,149 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::helpers::{Grid, ParseGridError};
+use crate::parsing::{self, ParseError};
+use std::fmt::{self, Display};
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// Puzzle input with normalized line endings: `\r\n` becomes `\n` and trailing newlines are removed.
+/// Anything else is kept as is, including leading whitespace such as the indentation of a diagram.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Input {
+    text: String,
+}
+
+impl Input {
+    pub fn new(raw: &str) -> Self {
+        let mut text = raw.replace("\r\n", "\n");
+        text.truncate(text.trim_end_matches('\n').len());
+        Input { text }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Parses every line as `T`. Errors get the number of the line.
+    pub fn lines_typed<T: FromStr>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T::Err: Display,
+    {
+        parsing::parse_lines(self, parsing::parse_value)
+    }
+
+    /// Parts of the input separated by blank lines.
+    pub fn paragraphs(&self) -> impl Iterator<Item = &str> {
+        parsing::paragraphs(self)
+    }
+
+    /// The input as a grid of characters.
+    pub fn grid(&self) -> Result<Grid<char>, ParseGridError> {
+        self.text.parse()
+    }
+
+    /// The input as a grid, converting every character with `f`, e.g. `|c| c.to_digit(10)`.
+    pub fn grid_with<T>(
+        &self,
+        f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseGridError> {
+        Grid::parse_with(self, f)
+    }
+
+    /// All integers of the input, including negative ones. See [`parsing::ints`].
+    pub fn ints<T: FromStr>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T::Err: Display,
+    {
+        parsing::ints(self)
+    }
+
+    /// 64-bit FNV-1a hash of the normalized text.
+    /// It does not change between runs or platforms, so inputs that only differ in line endings hash the same.
+    pub fn content_hash(&self) -> u64 {
+        self.text.bytes().fold(0xcbf29ce484222325, |hash, b| {
+            (hash ^ b as u64).wrapping_mul(0x100000001b3)
+        })
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl AsRef<str> for Input {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl From<&str> for Input {
+    fn from(raw: &str) -> Self {
+        Input::new(raw)
+    }
+}
+
+impl From<String> for Input {
+    fn from(raw: String) -> Self {
+        Input::new(&raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(Input::new("1\r\n2\r\n\r\n").as_str(), "1\n2");
+        assert_eq!(
+            Input::new("    [D]\n[N] [C]\n").as_str(),
+            "    [D]\n[N] [C]"
+        );
+        assert_eq!(Input::new("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n").len(), 30);
+        assert_eq!(Input::new("\n\n").as_str(), "");
+        assert_eq!(
+            Input::new("a\r\nb\n").content_hash(),
+            Input::new("a\nb").content_hash()
+        );
+        assert_ne!(
+            Input::new("a\nb").content_hash(),
+            Input::new("b\na").content_hash()
+        );
+        assert_eq!(Input::new("").content_hash(), 0xcbf29ce484222325);
+        assert_eq!(Input::new("a").content_hash(), 0xaf63dc4c8601ec8c);
+    }
+
+    #[test]
+    fn test_helpers() {
+        let input = Input::new("1000\n2000\n\n-3000\r\n");
+        assert_eq!(
+            input.paragraphs().collect::<Vec<_>>(),
+            ["1000\n2000", "-3000"]
+        );
+        assert_eq!(input.ints::<i32>().unwrap(), [1000, 2000, -3000]);
+
+        let err = input.lines_typed::<i32>().unwrap_err();
+        assert_eq!(err.line, Some(3));
+        let lines: Vec<String> = input.lines_typed().unwrap();
+        assert_eq!(lines, ["1000", "2000", "", "-3000"]);
+
+        let input = Input::from("30373\n25512\n");
+        assert_eq!(input.grid().unwrap()[(1, 2)], '5');
+        let digits = input.grid_with(|c| c.to_digit(10)).unwrap();
+        assert_eq!((digits.width(), digits.height()), (5, 2));
+        assert!(Input::new("12\n3").grid().is_err());
+    }
+}
@@ -0,0 +1,350 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::aoc::{self, Client};
+use crate::unlock::unlock_time;
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Deserializer};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Advent of Code asks to fetch a leaderboard at most once every 15 minutes.
+pub const MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// Members that do not share their name have none.
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    /// Stars keyed by day and part.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+impl Member {
+    /// The name as shown on the website.
+    pub fn name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// When the member got the star for a part.
+    pub fn star(&self, day: u8, part: u8) -> Option<DateTime<Utc>> {
+        let star = self.completion_day_level.get(&day)?.get(&part)?;
+        Utc.timestamp_opt(star.get_star_ts, 0).single()
+    }
+
+    fn last_star(&self) -> Option<i64> {
+        self.completion_day_level
+            .values()
+            .flat_map(|parts| parts.values())
+            .map(|s| s.get_star_ts)
+            .max()
+    }
+}
+
+fn year_from_str<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i32, D::Error> {
+    let event = String::deserialize(deserializer)?;
+    event.parse().map_err(serde::de::Error::custom)
+}
+
+/// A private leaderboard as served by `/<year>/leaderboard/private/view/<id>.json`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    #[serde(rename = "event", deserialize_with = "year_from_str")]
+    pub year: i32,
+    pub owner_id: u64,
+    pub members: BTreeMap<String, Member>,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Members ordered by id.
+    pub fn members(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|m| m.id);
+        members
+    }
+
+    /// Days on which at least one member got a star, in order.
+    pub fn days(&self) -> Vec<u8> {
+        (1..=25)
+            .filter(|day| self.members.values().any(|m| m.star(*day, 1).is_some()))
+            .collect()
+    }
+
+    /// Time from the unlock of a day to a member's star.
+    pub fn completion_time(&self, member: &Member, day: u8, part: u8) -> Option<Duration> {
+        (member.star(day, part)? - unlock_time(self.year, day))
+            .to_std()
+            .ok()
+    }
+}
+
+/// Fetches the JSON of a private leaderboard. Recent responses are served from the cache.
+pub fn fetch(client: &Client, year: i32, id: u64) -> Result<String, aoc::Error> {
+    client.get_recent(
+        &format!("/{}/leaderboard/private/view/{}.json", year, id),
+        MAX_AGE,
+    )
+}
+
+/// Completion times of a member on one day, measured from the unlock.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayTimes {
+    pub name: String,
+    pub part_one: Option<Duration>,
+    pub part_two: Option<Duration>,
+}
+
+impl DayTimes {
+    /// Time between the two stars.
+    pub fn delta(&self) -> Option<Duration> {
+        Some(self.part_two? - self.part_one?)
+    }
+}
+
+/// Completion times of every member with a star on `day`, fastest first.
+pub fn day_times(board: &Leaderboard, day: u8) -> Vec<DayTimes> {
+    let mut times: Vec<DayTimes> = board
+        .members()
+        .into_iter()
+        .map(|m| DayTimes {
+            name: m.name(),
+            part_one: board.completion_time(m, day, 1),
+            part_two: board.completion_time(m, day, 2),
+        })
+        .filter(|t| t.part_one.is_some())
+        .collect();
+    // members with both stars come first.
+    times.sort_by_key(|t| (t.part_two.is_none(), t.part_two, t.part_one));
+    times
+}
+
+/// How points are awarded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scoring {
+    /// The local score of the website: for every star, the first member gets as many points as
+    /// the leaderboard has members, the second one point less, and so on.
+    Local,
+    /// One point per star.
+    Stars,
+    /// Points like the local score, but only for part two, ranked by the time it took after part one.
+    /// Does not depend on when a member starts a puzzle.
+    Delta,
+}
+
+impl FromStr for Scoring {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "local" => Ok(Scoring::Local),
+            "stars" => Ok(Scoring::Stars),
+            "delta" => Ok(Scoring::Delta),
+            _ => Err(format!(
+                "unknown scoring \"{}\", expected local, stars or delta",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for Scoring {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Scoring::Local => "local",
+            Scoring::Stars => "stars",
+            Scoring::Delta => "delta",
+        };
+        f.pad(s)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Standing {
+    pub name: String,
+    pub stars: u32,
+    pub score: u64,
+}
+
+/// Awards points to the members in order of `key`, the smallest getting the most. Members without a key get none.
+fn award<K: Ord>(scores: &mut [u64], members: &[&Member], key: impl Fn(&Member) -> Option<K>) {
+    let mut ranked: Vec<(K, usize)> = members
+        .iter()
+        .enumerate()
+        .filter_map(|(i, m)| Some((key(m)?, i)))
+        .collect();
+    ranked.sort();
+    for (rank, (_, i)) in ranked.into_iter().enumerate() {
+        scores[i] += (members.len() - rank) as u64;
+    }
+}
+
+/// Members ranked by their score, ties broken by who got their last star first.
+pub fn ranking(board: &Leaderboard, scoring: Scoring) -> Vec<Standing> {
+    let members = board.members();
+    let mut scores = vec![0; members.len()];
+    for day in 1..=25 {
+        match scoring {
+            Scoring::Local => {
+                for part in [1, 2] {
+                    award(&mut scores, &members, |m| m.star(day, part));
+                }
+            }
+            Scoring::Stars => {
+                for (score, m) in scores.iter_mut().zip(&members) {
+                    *score += [1, 2].iter().filter(|&&p| m.star(day, p).is_some()).count() as u64;
+                }
+            }
+            Scoring::Delta => award(&mut scores, &members, |m| {
+                Some(m.star(day, 2)? - m.star(day, 1)?)
+            }),
+        }
+    }
+
+    let mut ranked: Vec<(u64, Option<i64>, &Member)> = members
+        .iter()
+        .zip(scores)
+        .map(|(m, score)| (score, m.last_star(), *m))
+        .collect();
+    ranked.sort_by_key(|&(score, last_star, m)| {
+        (
+            std::cmp::Reverse(score),
+            last_star.is_none(),
+            last_star,
+            m.id,
+        )
+    });
+    ranked
+        .into_iter()
+        .map(|(score, _, m)| Standing {
+            name: m.name(),
+            stars: m.stars,
+            score,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/leaderboard.json");
+
+    fn scores(board: &Leaderboard, scoring: Scoring) -> Vec<(String, u64)> {
+        ranking(board, scoring)
+            .into_iter()
+            .map(|s| (s.name, s.score))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let board = Leaderboard::parse(FIXTURE).unwrap();
+        assert_eq!(board.year, 2022);
+        assert_eq!(board.members.len(), 4);
+        assert_eq!(board.days(), [1, 2]);
+
+        let anonymous = &board.members["3"];
+        assert_eq!(anonymous.name(), "(anonymous user #3)");
+        assert_eq!(
+            board.completion_time(anonymous, 1, 2),
+            Some(Duration::from_secs(5100))
+        );
+        assert_eq!(board.completion_time(anonymous, 2, 1), None);
+
+        assert!(Leaderboard::parse("<html></html>").is_err());
+        assert!(Leaderboard::parse(r#"{"event": "next", "owner_id": 1, "members": {}}"#).is_err());
+    }
+
+    #[test]
+    fn test_day_times() {
+        let board = Leaderboard::parse(FIXTURE).unwrap();
+        let times = day_times(&board, 2);
+        assert_eq!(
+            times,
+            [
+                DayTimes {
+                    name: "Alice".into(),
+                    part_one: Some(Duration::from_secs(1000)),
+                    part_two: Some(Duration::from_secs(1200)),
+                },
+                DayTimes {
+                    name: "Bob".into(),
+                    part_one: Some(Duration::from_secs(800)),
+                    part_two: None,
+                },
+            ]
+        );
+        assert_eq!(times[0].delta(), Some(Duration::from_secs(200)));
+        assert_eq!(times[1].delta(), None);
+
+        let names: Vec<String> = day_times(&board, 1).into_iter().map(|t| t.name).collect();
+        assert_eq!(names, ["Alice", "Bob", "(anonymous user #3)"]);
+    }
+
+    #[test]
+    fn test_local_score() {
+        let board = Leaderboard::parse(FIXTURE).unwrap();
+        let ranking = ranking(&board, Scoring::Local);
+        // the computed scores match the ones of the website.
+        for standing in &ranking {
+            let member = board
+                .members
+                .values()
+                .find(|m| m.name() == standing.name)
+                .unwrap();
+            assert_eq!(standing.score, member.local_score);
+        }
+        assert_eq!(
+            scores(&board, Scoring::Local),
+            [
+                ("Alice".into(), 14),
+                ("Bob".into(), 11),
+                ("(anonymous user #3)".into(), 4),
+                ("Dana".into(), 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_alternative_scoring() {
+        let board = Leaderboard::parse(FIXTURE).unwrap();
+        assert_eq!(
+            scores(&board, Scoring::Stars),
+            [
+                ("Alice".into(), 4),
+                ("Bob".into(), 3),
+                ("(anonymous user #3)".into(), 2),
+                ("Dana".into(), 0)
+            ]
+        );
+        assert_eq!(
+            scores(&board, Scoring::Delta),
+            [
+                ("Alice".into(), 7),
+                ("(anonymous user #3)".into(), 4),
+                ("Bob".into(), 2),
+                ("Dana".into(), 0)
+            ]
+        );
+        assert_eq!("delta".parse(), Ok(Scoring::Delta));
+        assert!("global".parse::<Scoring>().is_err());
+    }
+}
@@ -2,45 +2,224 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::bench::{BENCH_BUDGET_ENV, BENCH_ENV};
+use advent_of_code::config;
+use advent_of_code::days;
+use advent_of_code::history::{self, Run, Timing, HISTORY_FILE};
+use advent_of_code::results::{self, Status, PARSE_PART};
+use advent_of_code::runner::{self, DayRun, Limits, Outcome};
 use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+const DEFAULT_THRESHOLD: f64 = 20.0;
+
+struct Args {
+    year: Option<i32>,
+    all_years: bool,
+    bench: bool,
+    budget_ms: Option<u64>,
+    history: Option<PathBuf>,
+    threshold: f64,
+    jobs: Option<usize>,
+    days: RangeInclusive<u8>,
+    limits: Limits,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let no_history = args.contains("--no-history");
+    let history = args
+        .opt_value_from_str("--history")?
+        .unwrap_or_else(|| advent_of_code::project_dir().join(HISTORY_FILE));
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        all_years: args.contains("--all-years"),
+        bench: args.contains("--bench"),
+        budget_ms: args.opt_value_from_str("--budget")?,
+        history: if no_history { None } else { Some(history) },
+        threshold: args
+            .opt_value_from_str("--threshold")?
+            .unwrap_or(DEFAULT_THRESHOLD),
+        jobs: args.opt_value_from_str(["-j", "--jobs"])?,
+        days: args
+            .opt_value_from_fn("--days", runner::parse_days)?
+            .unwrap_or(1..=25),
+        limits: Limits {
+            timeout: match args.opt_value_from_str::<_, u64>("--timeout")? {
+                Some(0) => None,
+                Some(secs) => Some(Duration::from_secs(secs)),
+                None => Some(runner::DEFAULT_TIMEOUT),
+            },
+            memory_mb: args.opt_value_from_str("--memory")?,
+            cpu_secs: args.opt_value_from_str("--cpu")?,
+        },
+    })
+}
+
+fn print_day(day: u8, run: &DayRun) {
+    println!("----------");
+    println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+    println!("----------");
+
+    let stdout = run.stdout.trim();
+    if !stdout.is_empty() {
+        println!("{}", stdout);
+    }
+
+    match &run.outcome {
+        Outcome::Completed if !stdout.is_empty() => (),
+        Outcome::Completed | Outcome::NoInput => println!("Not solved."),
+        outcome => println!("❌ {}{}{}", ANSI_BOLD, outcome, ANSI_RESET),
+    }
+}
+
+/// Combined report over several years: solved parts and total time of each year.
+fn print_years(years: &[i32], timings: &[Timing]) {
+    println!("----------");
+    println!("{}Year  Solved  Time{}", ANSI_BOLD, ANSI_RESET);
+    for &year in years {
+        let of_year: Vec<&Timing> = timings.iter().filter(|t| t.year == year).collect();
+        let solved = of_year.iter().filter(|t| t.part != PARSE_PART).count();
+        let total_ns: u64 = of_year.iter().map(|t| t.duration_ns).sum();
+        println!(
+            "{:<5} {:<7} {:.2?}",
+            year,
+            solved,
+            Duration::from_nanos(total_ns)
+        );
+    }
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
-            );
-
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let years = if args.all_years {
+        days::years()
+    } else {
+        let config = match config::load() {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Failed to load configuration: {}", e);
+                process::exit(1);
+            }
+        };
+        match args.year.map_or_else(|| config.year(), Ok) {
+            Ok(year) => vec![year],
+            Err(e) => {
+                eprintln!(
+                    "Failed to determine the year: {}, or pass `--year <year>`.",
+                    e
+                );
+                process::exit(1);
             }
+        }
+    };
+
+    let days: Vec<(i32, u8)> = runner::available_days(&years)
+        .into_iter()
+        .filter(|(_, day)| args.days.contains(day))
+        .collect();
+
+    let exe = match runner::build() {
+        Ok(exe) => exe,
+        Err(e) => {
+            eprintln!("Failed to build solutions: {}", e);
+            process::exit(1);
+        }
+    };
+
+    // timings of days that run at the same time affect each other, so they are neither
+    // benchmarked nor recorded in the history.
+    let jobs = match args.jobs {
+        _ if args.bench => 1,
+        Some(jobs) => jobs,
+        None if args.history.is_some() => 1,
+        None => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+    if args.bench && args.jobs.is_some_and(|j| j > 1) {
+        println!("Ignoring `--jobs`, days are benchmarked one at a time.");
+    }
+
+    let mut envs = vec![];
+    if args.bench {
+        envs.push((BENCH_ENV, "1".to_string()));
+    }
+    if let Some(budget_ms) = args.budget_ms {
+        envs.push((BENCH_BUDGET_ENV, budget_ms.to_string()));
+    }
+
+    let mut current_year = None;
+    let timings: Vec<Timing> = runner::run_days(&exe, days, jobs, envs, args.limits)
+        .flat_map(|((year, day), run)| {
+            if years.len() > 1 && current_year != Some(year) {
+                println!("{}🎄 {} 🎄{}", ANSI_BOLD, year, ANSI_RESET);
+                current_year = Some(year);
+            }
+            print_day(day, &run);
+            run.records
+        })
+        .filter(|r| r.status == Status::Solved)
+        .map(|r| Timing {
+            year: r.year,
+            day: r.day,
+            part: r.part,
+            duration_ns: r.bench.as_ref().map_or(r.duration_ns, |b| b.median_ns),
         })
-        .sum();
+        .collect();
+
+    if years.len() > 1 {
+        print_years(&years, &timings);
+    }
+
+    let total_ns: u64 = timings.iter().map(|t| t.duration_ns).sum();
 
     println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+        "{}Total{}:{} {}{:.2?}{}",
+        ANSI_BOLD,
+        if args.bench { " (sum of medians)" } else { "" },
+        ANSI_RESET,
+        ANSI_ITALIC,
+        Duration::from_nanos(total_ns),
+        ANSI_RESET
     );
+
+    let Some(history_path) = &args.history else {
+        return;
+    };
+    if jobs > 1 {
+        println!("Timing history is only recorded for runs with `-j 1`.");
+        return;
+    }
+
+    let history = history::read_history(history_path).unwrap_or_else(|e| {
+        eprintln!("could not read timing history: {}", e);
+        vec![]
+    });
+    let run = Run::new(args.bench, timings);
+
+    for regression in history::find_regressions(&history, &run, args.threshold) {
+        println!(
+            "⚠️  {} day {:02} {} regressed: {:.2?} (baseline: {:.2?}, +{:.0}%)",
+            regression.year,
+            regression.day,
+            results::part_name(regression.part),
+            Duration::from_nanos(regression.duration_ns),
+            Duration::from_nanos(regression.baseline_ns),
+            regression.slowdown()
+        );
+    }
+
+    if let Err(e) = history::append_run(history_path, &run) {
+        eprintln!("could not write timing history: {}", e);
+    }
 }
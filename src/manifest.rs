@@ -0,0 +1,297 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::DayAnswers;
+use crate::Input;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Expected answers per example, keyed by the example's file name without extension.
+pub const MANIFEST_FILE: &str = "examples.toml";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: DayAnswers,
+}
+
+impl Example {
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.expected.part_one.as_deref(),
+            2 => self.expected.part_two.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Returns true if `name` is the default example of a day (`09`) or a named one (`09-a`).
+fn belongs_to(name: &str, day: u8) -> bool {
+    let padded = format!("{:02}", day);
+    match name.strip_prefix(&padded) {
+        Some(rest) => rest.is_empty() || rest.starts_with('-'),
+        None => false,
+    }
+}
+
+pub fn parse_manifest(contents: &str) -> Result<BTreeMap<String, DayAnswers>, toml::de::Error> {
+    toml::from_str(contents)
+}
+
+fn examples_dir(year: i32) -> PathBuf {
+    crate::config::get().examples_dir(year)
+}
+
+/// Index of the header line of an entry, written either as `["09-a"]` or `[09-a]`.
+fn find_header(lines: &[&str], name: &str) -> Option<usize> {
+    let quoted = format!("[\"{}\"]", name);
+    let bare = format!("[{}]", name);
+    lines
+        .iter()
+        .position(|l| l.trim() == quoted || l.trim() == bare)
+}
+
+fn format_entry(name: &str, expected: &DayAnswers) -> Vec<String> {
+    let mut lines = vec![format!("[\"{}\"]", name)];
+    for (key, answer) in [
+        ("part_one", &expected.part_one),
+        ("part_two", &expected.part_two),
+    ] {
+        if let Some(answer) = answer {
+            lines.push(format!("{} = {}", key, toml::Value::from(answer.as_str())));
+        }
+    }
+    lines
+}
+
+/// Adds an entry to the manifest. An existing entry only gets the expected answers it does not list yet.
+/// Other entries and comments are kept as they are.
+pub fn add_entry(
+    contents: &str,
+    name: &str,
+    expected: &DayAnswers,
+) -> Result<String, toml::de::Error> {
+    let manifest = parse_manifest(contents)?;
+    let mut lines: Vec<&str> = contents.lines().collect();
+
+    let entry = match manifest.get(name) {
+        None => {
+            while lines.last().is_some_and(|l| l.trim().is_empty()) {
+                lines.pop();
+            }
+            let mut lines: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+            if !lines.is_empty() {
+                lines.push(String::new());
+            }
+            lines.extend(format_entry(name, expected));
+            return Ok(lines.join("\n") + "\n");
+        }
+        Some(existing) => DayAnswers {
+            part_one: existing.part_one.clone().or(expected.part_one.clone()),
+            part_two: existing.part_two.clone().or(expected.part_two.clone()),
+        },
+    };
+
+    // replace the header and keys of the existing entry.
+    let start = find_header(&lines, name).expect("entry without header");
+    let end = lines[start + 1..]
+        .iter()
+        .position(|l| l.trim_start().starts_with('[') || l.trim().is_empty())
+        .map_or(lines.len(), |i| start + 1 + i);
+    let formatted = format_entry(name, &entry);
+    lines.splice(start..end, formatted.iter().map(String::as_str));
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Writes extracted examples to `src/examples/<year>` and adds them to the manifest.
+/// Example files that already have contents are kept.
+///
+/// If a day has several examples, the scaffolded default example (`NN`) is removed as long as it is still empty.
+pub fn save_examples(year: i32, day: u8, examples: &[Example]) -> io::Result<()> {
+    let folder = examples_dir(year);
+    fs::create_dir_all(&folder)?;
+    let manifest_path = folder.join(MANIFEST_FILE);
+    let mut contents = fs::read_to_string(&manifest_path).unwrap_or_default();
+    let invalid = |e| io::Error::new(io::ErrorKind::InvalidData, e);
+
+    for example in examples {
+        let path = folder.join(format!("{}.txt", example.name));
+        let is_empty = fs::read_to_string(&path).map_or(true, |c| c.trim().is_empty());
+        if is_empty {
+            fs::write(&path, &example.input)?;
+        }
+        contents = add_entry(&contents, &example.name, &example.expected).map_err(invalid)?;
+    }
+
+    let default_name = format!("{:02}", day);
+    let default_path = folder.join(format!("{}.txt", default_name));
+    let manifest = parse_manifest(&contents).map_err(invalid)?;
+    let unused_default = !examples.iter().any(|e| e.name == default_name)
+        && manifest.get(&default_name) == Some(&DayAnswers::default())
+        && fs::read_to_string(&default_path).is_ok_and(|c| c.trim().is_empty());
+    if unused_default {
+        let mut lines: Vec<&str> = contents.lines().collect();
+        if let Some(header) = find_header(&lines, &default_name) {
+            lines.remove(header);
+            if lines.get(header).is_some_and(|l| l.trim().is_empty()) {
+                lines.remove(header);
+            }
+        }
+        contents = lines.join("\n") + "\n";
+        fs::remove_file(&default_path)?;
+    }
+
+    fs::write(&manifest_path, contents)
+}
+
+/// Loads all examples of a day that are listed in `src/examples/<year>/examples.toml`.
+pub fn examples(year: i32, day: u8) -> Vec<Example> {
+    let folder = examples_dir(year);
+    let contents =
+        fs::read_to_string(folder.join(MANIFEST_FILE)).expect("could not open example manifest");
+    let manifest = parse_manifest(&contents).expect("could not parse example manifest");
+
+    manifest
+        .into_iter()
+        .filter(|(name, _)| belongs_to(name, day))
+        .map(|(name, expected)| {
+            let path = folder.join(format!("{}.txt", name));
+            let input = fs::read_to_string(&path)
+                .unwrap_or_else(|e| panic!("could not open example file {:?}: {}", path, e));
+            Example {
+                name,
+                input,
+                expected,
+            }
+        })
+        .collect()
+}
+
+/// Runs `solver` against every example of a day and panics with a list of mismatches.
+///
+/// If no example lists an expected answer for `part`, the part is expected to be unsolved.
+pub fn check<T: Display>(year: i32, day: u8, part: u8, solver: impl Fn(&Input) -> Option<T>) {
+    let examples = examples(year, day);
+    assert!(
+        !examples.is_empty(),
+        "no examples listed for day {:02} of {}",
+        day,
+        year
+    );
+
+    let unsolved = examples.iter().all(|e| e.expected(part).is_none());
+    let failures: Vec<String> = examples
+        .iter()
+        .filter(|e| unsolved || e.expected(part).is_some())
+        .filter_map(|e| {
+            let expected = e.expected(part);
+            let actual = solver(&Input::new(&e.input)).map(|a| a.to_string());
+            if actual.as_deref() == expected {
+                return None;
+            }
+            Some(format!(
+                "example \"{}\", part {}: expected {:?}, got {:?}",
+                e.name, part, expected, actual
+            ))
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Generates `test_part_one` and `test_part_two`, which check a solution against its examples.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+            use $crate::Solution;
+
+            #[test]
+            fn test_part_one() {
+                $crate::manifest::check(<$solution>::YEAR, <$solution>::DAY, 1, |input| {
+                    <$solution>::part_one(&<$solution>::parse(input))
+                });
+            }
+
+            #[test]
+            fn test_part_two() {
+                $crate::manifest::check(<$solution>::YEAR, <$solution>::DAY, 2, |input| {
+                    <$solution>::part_two(&<$solution>::parse(input))
+                });
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_belongs_to() {
+        assert!(belongs_to("09", 9));
+        assert!(belongs_to("09-a", 9));
+        assert!(belongs_to("09-larger", 9));
+        assert!(!belongs_to("19", 9));
+        assert!(!belongs_to("09a", 9));
+        assert!(!belongs_to("10", 1));
+    }
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest = parse_manifest(
+            r#"
+["09-a"]
+part_one = "13"
+
+["09-b"]
+part_one = "88"
+part_two = "36"
+"#,
+        )
+        .unwrap();
+        assert_eq!(manifest["09-a"].part_one.as_deref(), Some("13"));
+        assert_eq!(manifest["09-a"].part_two, None);
+        assert_eq!(manifest["09-b"].part_two.as_deref(), Some("36"));
+    }
+
+    #[test]
+    fn test_add_entry() {
+        let contents = "# expected answers\n[01]\npart_one = \"24000\"\n\n[\"02\"]\n";
+        let expected = DayAnswers {
+            part_one: Some("15".to_string()),
+            part_two: Some("12".to_string()),
+        };
+
+        // a new entry is appended.
+        assert_eq!(
+            add_entry(contents, "03", &expected).unwrap(),
+            "# expected answers\n[01]\npart_one = \"24000\"\n\n[\"02\"]\n\n[\"03\"]\npart_one = \"15\"\npart_two = \"12\"\n"
+        );
+        // an entry without expected answers gets them.
+        assert_eq!(
+            add_entry(contents, "02", &expected).unwrap(),
+            "# expected answers\n[01]\npart_one = \"24000\"\n\n[\"02\"]\npart_one = \"15\"\npart_two = \"12\"\n"
+        );
+        // existing expected answers are kept.
+        assert_eq!(
+            add_entry(contents, "01", &expected).unwrap(),
+            "# expected answers\n[\"01\"]\npart_one = \"24000\"\npart_two = \"12\"\n\n[\"02\"]\n"
+        );
+    }
+
+    #[test]
+    fn test_examples_manifest() {
+        let examples = examples(2022, 9);
+        let names: Vec<&str> = examples.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["09-a", "09-b"]);
+        assert_eq!(examples[0].expected(1), Some("13"));
+    }
+}
@@ -0,0 +1,455 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::any::type_name;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Placeholder of a [`scan`] pattern.
+pub const PLACEHOLDER: &str = "{}";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the input, counting from 1, if known.
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    /// Locates the error in a part of the input that starts at `line`.
+    /// An error that already has a line number is counted relative to that part.
+    pub fn at(self, line: usize) -> Self {
+        ParseError {
+            line: Some(self.line.map_or(line, |l| line + l - 1)),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Name of a type without its module path, e.g. `String` instead of `alloc::string::String`.
+fn short_type_name<T>() -> &'static str {
+    let name = type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Parses a value, describing the value and the expected type on failure.
+pub fn parse_value<T: FromStr>(s: &str) -> Result<T, ParseError>
+where
+    T::Err: Display,
+{
+    s.parse().map_err(|e| {
+        ParseError::new(format!(
+            "could not parse {:?} as {}: {}",
+            s,
+            short_type_name::<T>(),
+            e
+        ))
+    })
+}
+
+/// Matches `input` against a pattern with `{}` placeholders and returns the text of each placeholder.
+///
+/// The text between placeholders has to match exactly. A placeholder ends at the first occurrence of the text after it,
+/// the last one at the end of the input.
+pub fn scan<'a>(pattern: &str, input: &'a str) -> Result<Vec<&'a str>, ParseError> {
+    let mut literals = pattern.split(PLACEHOLDER);
+    let first = literals.next().unwrap();
+    let mismatch = || ParseError::new(format!("{:?} does not match {:?}", input, pattern));
+
+    let mut rest = input.strip_prefix(first).ok_or_else(mismatch)?;
+    let mut captures = vec![];
+    let literals: Vec<&str> = literals.collect();
+    for (i, literal) in literals.iter().enumerate() {
+        let is_last = i == literals.len() - 1;
+        let end = match (literal.is_empty(), is_last) {
+            (true, true) => rest.len(),
+            (true, false) => {
+                return Err(ParseError::new(format!(
+                    "pattern {:?} has adjacent placeholders",
+                    pattern
+                )))
+            }
+            (false, true) => rest.strip_suffix(literal).ok_or_else(mismatch)?.len(),
+            (false, false) => rest.find(literal).ok_or_else(mismatch)?,
+        };
+        captures.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+    // a pattern without placeholders has to match the whole input.
+    if !rest.is_empty() {
+        return Err(mismatch());
+    }
+    Ok(captures)
+}
+
+/// Types that can be built from the placeholders of a [`scan`] pattern: tuples of [`FromStr`] types.
+pub trait FromCaptures: Sized {
+    fn from_captures(captures: &[&str]) -> Result<Self, ParseError>;
+}
+
+macro_rules! tuple_from_captures {
+    ($len:expr; $($t:ident $i:tt),+) => {
+        impl<$($t: FromStr),+> FromCaptures for ($($t,)+)
+        where
+            $($t::Err: Display),+
+        {
+            fn from_captures(captures: &[&str]) -> Result<Self, ParseError> {
+                if captures.len() != $len {
+                    return Err(ParseError::new(format!(
+                        "the pattern has {} placeholders, but {} values are expected",
+                        captures.len(),
+                        $len
+                    )));
+                }
+                Ok(($(parse_value::<$t>(captures[$i])?,)+))
+            }
+        }
+    };
+}
+
+tuple_from_captures!(1; A 0);
+tuple_from_captures!(2; A 0, B 1);
+tuple_from_captures!(3; A 0, B 1, C 2);
+tuple_from_captures!(4; A 0, B 1, C 2, D 3);
+tuple_from_captures!(5; A 0, B 1, C 2, D 3, E 4);
+tuple_from_captures!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+tuple_from_captures!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple_from_captures!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Matches `input` against a pattern like [`scan`] and parses the placeholders into a tuple.
+pub fn scan_into<T: FromCaptures>(pattern: &str, input: &str) -> Result<T, ParseError> {
+    T::from_captures(&scan(pattern, input)?)
+}
+
+/// Matches a line against a pattern with `{}` placeholders and parses them into a tuple.
+///
+/// The types are inferred, or given after `=>`:
+///
+/// ```
+/// # use advent_of_code::scan;
+/// let (count, from, to): (usize, usize, usize) = scan!("move {} from {} to {}", "move 1 from 2 to 1").unwrap();
+/// let (name, size) = scan!("{} {}", "b.txt 14848514" => String, u64).unwrap();
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($pattern:literal, $input:expr) => {
+        $crate::parsing::scan_into($pattern, $input)
+    };
+    ($pattern:literal, $input:expr => $($t:ty),+) => {
+        $crate::parsing::scan_into::<($($t,)+)>($pattern, $input)
+    };
+}
+
+/// Parts of the input separated by blank lines.
+pub fn paragraphs(input: &str) -> impl Iterator<Item = &str> {
+    paragraphs_with_lines(input).map(|(_, paragraph)| paragraph)
+}
+
+/// Paragraphs with the line each of them starts at.
+fn paragraphs_with_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut start: Option<(usize, usize)> = None;
+    let mut end = 0;
+    let mut paragraphs = vec![];
+    let mut offset = 0;
+    for (i, line) in input.split_inclusive('\n').enumerate() {
+        if line.trim().is_empty() {
+            if let Some((line, from)) = start.take() {
+                paragraphs.push((line, &input[from..end]));
+            }
+        } else {
+            start.get_or_insert((i + 1, offset));
+            end = offset + line.trim_end_matches(['\r', '\n']).len();
+        }
+        offset += line.len();
+    }
+    if let Some((line, from)) = start {
+        paragraphs.push((line, &input[from..end]));
+    }
+    paragraphs.into_iter()
+}
+
+/// Parses every line with `f`. Errors get the number of the line.
+pub fn parse_lines<T>(
+    input: &str,
+    mut f: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.at(i + 1)))
+        .collect()
+}
+
+/// Parses every paragraph with `f`. Errors get line numbers relative to the whole input.
+pub fn parse_paragraphs<T>(
+    input: &str,
+    mut f: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    paragraphs_with_lines(input)
+        .map(|(line, paragraph)| f(paragraph).map_err(|e| e.at(line)))
+        .collect()
+}
+
+/// All integers in `s`, in order. A `-` directly before a number is a sign, unless it follows a digit as in `2-4`.
+pub fn ints<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError>
+where
+    T::Err: Display,
+{
+    let bytes = s.as_bytes();
+    let mut ints = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let is_sign = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit());
+        if !is_sign && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        ints.push(parse_value(&s[start..i])?);
+    }
+    Ok(ints)
+}
+
+/// A block of `key: value` lines, such as a paragraph describing a monkey. Indentation is ignored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyValues<'a> {
+    /// Line, key and value of each entry.
+    entries: Vec<(usize, &'a str, &'a str)>,
+}
+
+impl<'a> KeyValues<'a> {
+    pub fn parse(block: &'a str) -> Result<Self, ParseError> {
+        let entries = block
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| match line.split_once(':') {
+                Some((key, value)) => Ok((i + 1, key.trim(), value.trim())),
+                None => Err(
+                    ParseError::new(format!("expected `key: value`, found {:?}", line)).at(i + 1),
+                ),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(KeyValues { entries })
+    }
+
+    fn entry(&self, key: &str) -> Result<(usize, &'a str), ParseError> {
+        self.entries
+            .iter()
+            .find(|(_, k, _)| *k == key)
+            .map(|&(line, _, value)| (line, value))
+            .ok_or_else(|| ParseError::new(format!("missing key {:?}", key)))
+    }
+
+    pub fn get(&self, key: &str) -> Result<&'a str, ParseError> {
+        self.entry(key).map(|(_, value)| value)
+    }
+
+    /// Parses the value of `key` with `f`. Errors get the line of the entry.
+    pub fn get_with<T>(
+        &self,
+        key: &str,
+        f: impl FnOnce(&'a str) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        let (line, value) = self.entry(key)?;
+        f(value).map_err(|e| e.at(line))
+    }
+
+    /// Matches the value of `key` against a pattern like [`scan!`](crate::scan).
+    pub fn scan<T: FromCaptures>(&self, key: &str, pattern: &str) -> Result<T, ParseError> {
+        self.get_with(key, |value| scan_into(pattern, value))
+    }
+
+    /// Keys in order.
+    pub fn keys(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.entries.iter().map(|(_, key, _)| *key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan() {
+        assert_eq!(
+            scan("move {} from {} to {}", "move 11 from 2 to 1").unwrap(),
+            ["11", "2", "1"]
+        );
+        assert_eq!(
+            scan("{}-{},{}-{}", "2-4,6-8").unwrap(),
+            ["2", "4", "6", "8"]
+        );
+        assert_eq!(scan("$ cd {}", "$ cd a b").unwrap(), ["a b"]);
+        assert_eq!(scan("Valve {} has", "Valve AA has").unwrap(), ["AA"]);
+        assert!(scan("move {} from {}", "mv 1 from 2").is_err());
+        assert!(scan("{} to {}.", "1 to 2").is_err());
+        assert!(scan("{}{}", "12").is_err());
+        assert!(scan("noop", "noop").unwrap().is_empty());
+        assert!(scan("noop", "noop 1").is_err());
+
+        let (count, from, to): (usize, usize, usize) =
+            scan!("move {} from {} to {}", "move 11 from 2 to 1").unwrap();
+        assert_eq!((count, from, to), (11, 2, 1));
+        let (name, size) = scan!("{} {}", "b.txt 14848514" => String, u64).unwrap();
+        assert_eq!((name.as_str(), size), ("b.txt", 14848514));
+        assert_eq!(scan!("x={}", "x=-3" => i32), Ok((-3,)));
+    }
+
+    #[test]
+    fn test_scan_errors() {
+        let err = scan!("move {} from {}", "move x from 2" => usize, usize).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "could not parse \"x\" as usize: invalid digit found in string"
+        );
+        let err = scan!("{} {}", "1 2" => u8).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the pattern has 2 placeholders, but 1 values are expected"
+        );
+        let err = parse_lines("move 1\nmove two", |l| scan!("move {}", l => u8)).unwrap_err();
+        assert_eq!(err.line, Some(2));
+        assert!(err
+            .to_string()
+            .starts_with("line 2: could not parse \"two\" as u8"));
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let input = "a\nb\n\n\nc\r\n\r\nd\n";
+        assert_eq!(paragraphs(input).collect::<Vec<_>>(), ["a\nb", "c", "d"]);
+        assert_eq!(
+            paragraphs_with_lines(input)
+                .map(|(l, _)| l)
+                .collect::<Vec<_>>(),
+            [1, 5, 7]
+        );
+        assert_eq!(paragraphs("").count(), 0);
+
+        let err = parse_paragraphs(input, |p| parse_lines(p, parse_value::<u8>)).unwrap_err();
+        assert_eq!(err.line, Some(1));
+        let err = parse_paragraphs("1\n\n2\nx", |p| parse_lines(p, parse_value::<u8>)).unwrap_err();
+        assert_eq!(err.line, Some(4));
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<i32>("x=-3, y=12").unwrap(), [-3, 12]);
+        assert_eq!(ints::<u32>("2-4,6-8").unwrap(), [2, 4, 6, 8]);
+        assert_eq!(ints::<i64>("-1 - -2 -").unwrap(), [-1, -2]);
+        assert!(ints::<u8>("no numbers").unwrap().is_empty());
+        assert!(ints::<u8>("300").is_err());
+        assert!(ints::<u8>("at -1").is_err());
+    }
+
+    #[test]
+    fn test_key_values() {
+        let block = "Monkey 0:\n  Starting items: 79, 98\n  Test: divisible by 23\n    If true: throw to monkey 2";
+        let values = KeyValues::parse(block).unwrap();
+        assert_eq!(
+            values.keys().collect::<Vec<_>>(),
+            ["Monkey 0", "Starting items", "Test", "If true"]
+        );
+        assert_eq!(values.get("Monkey 0"), Ok(""));
+        assert_eq!(
+            values.get_with("Starting items", ints::<u32>),
+            Ok(vec![79, 98])
+        );
+        assert_eq!(values.scan("If true", "throw to monkey {}"), Ok((2usize,)));
+
+        assert_eq!(values.scan("Test", "divisible by {}"), Ok((23u8,)));
+        let err = values
+            .scan::<(String,)>("Test", "divided by {}")
+            .unwrap_err();
+        assert_eq!(err.line, Some(3));
+        let err = values.get("Operation").unwrap_err();
+        assert_eq!(err.line, None);
+        assert_eq!(err.to_string(), "missing key \"Operation\"");
+        let err = KeyValues::parse("a: 1\nb 2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2: expected `key: value`, found \"b 2\""
+        );
+    }
+
+    #[derive(Debug, PartialEq, crate::FromInput)]
+    #[pattern("{start}-{end}")]
+    struct Range {
+        start: u32,
+        end: u32,
+    }
+
+    #[derive(Debug, PartialEq, crate::FromInput)]
+    #[pattern("{1} <- {0}")]
+    struct Edge(String, String);
+
+    #[derive(Debug, PartialEq, crate::FromInput)]
+    enum Command {
+        #[pattern("$ cd {}")]
+        Cd(String),
+        #[pattern("$ ls")]
+        Ls,
+        // overlaps with `Dir`, which is tried when the size does not parse.
+        #[pattern("{size} {name}")]
+        File { name: String, size: u64 },
+        #[pattern("dir {}")]
+        Dir(String),
+    }
+
+    #[test]
+    fn test_derive() {
+        assert_eq!("2-4".parse(), Ok(Range { start: 2, end: 4 }));
+        assert_eq!("b <- a".parse(), Ok(Edge("a".to_string(), "b".to_string())));
+        assert_eq!("$ cd /".parse(), Ok(Command::Cd("/".to_string())));
+        assert_eq!("$ ls".parse(), Ok(Command::Ls));
+        assert_eq!(
+            "14848514 b.txt".parse(),
+            Ok(Command::File {
+                name: "b.txt".to_string(),
+                size: 14848514
+            })
+        );
+        let (a, b) = scan!("{},{}", "2-4,6-8" => Range, Range).unwrap();
+        assert_eq!((a.end, b.start), (4, 6));
+
+        assert_eq!(
+            "2-x".parse::<Range>().unwrap_err().to_string(),
+            "could not parse \"x\" as u32: invalid digit found in string"
+        );
+        assert_eq!("dir a".parse(), Ok(Command::Dir("a".to_string())));
+        assert_eq!(
+            "-1 a".parse::<Command>().unwrap_err().to_string(),
+            "\"-1 a\" does not match any pattern of Command"
+        );
+        assert_eq!(
+            "pwd".parse::<Command>().unwrap_err().to_string(),
+            "\"pwd\" does not match any pattern of Command"
+        );
+    }
+}
@@ -0,0 +1,406 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::DayAnswers;
+use crate::aoc::{self, Client};
+use crate::manifest::{self, Example};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use regex::Regex;
+use std::io;
+use std::path::PathBuf;
+
+const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+/// Puzzle descriptions are stored as `src/puzzles/<year>/<day>.md`, or in `paths.puzzles` of `aoc.toml`.
+pub fn puzzle_path(year: i32, day: u8) -> PathBuf {
+    crate::config::get()
+        .puzzles_dir(year)
+        .join(format!("{:02}.md", day))
+}
+
+/// Fetches the puzzle page of a day and converts its description to Markdown.
+pub fn fetch(client: &Client, year: i32, day: u8) -> Result<String, aoc::Error> {
+    let html = client.get(&format!("/{}/day/{}", year, day))?;
+    Ok(to_markdown(&html))
+}
+
+/// Whether the description contains part two, which is only shown once part one is solved.
+pub fn has_part_two(markdown: &str) -> bool {
+    markdown.contains(PART_TWO_HEADING)
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let pattern = format!("{}=\"", name);
+    let start = attributes.find(&pattern)? + pattern.len();
+    let end = attributes[start..].find('"')?;
+    Some(&attributes[start..start + end])
+}
+
+fn absolute_url(href: &str) -> String {
+    match href.strip_prefix('/') {
+        Some(path) => format!("{}/{}", aoc::DEFAULT_BASE_URL, path),
+        None => href.to_string(),
+    }
+}
+
+/// Converts the `<article class="day-desc">` elements of a puzzle page to Markdown.
+///
+/// Only the handful of tags used in puzzle descriptions is supported.
+pub fn to_markdown(html: &str) -> String {
+    let articles = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+    let tag = Regex::new(r"<(/?)([a-zA-Z0-9]+)([^>]*)>").unwrap();
+
+    let mut markdown = String::new();
+    for article in articles.captures_iter(html) {
+        let article = &article[1];
+
+        let mut pre = false;
+        // contents of an inline `<code>` and whether all of it is emphasized.
+        let mut code: Option<String> = None;
+        let mut links: Vec<String> = vec![];
+        let mut last = 0;
+
+        for captures in tag.captures_iter(article) {
+            let whole = captures.get(0).unwrap();
+            let text = decode_entities(&article[last..whole.start()]);
+            last = whole.end();
+
+            match &mut code {
+                Some(code) => code.push_str(&text),
+                None if pre => markdown.push_str(&text),
+                None => {
+                    // whitespace between block elements is not part of the text.
+                    let text = text.replace('\n', " ");
+                    if markdown.is_empty() || markdown.ends_with('\n') {
+                        markdown.push_str(text.trim_start());
+                    } else {
+                        markdown.push_str(&text);
+                    }
+                }
+            }
+
+            let closing = &captures[1] == "/";
+            match (&captures[2], closing) {
+                ("h2", false) => markdown.push_str("## "),
+                ("h2", true) | ("p", true) => markdown.push_str("\n\n"),
+                ("pre", false) => {
+                    pre = true;
+                    markdown.push_str("```\n");
+                }
+                ("pre", true) => {
+                    pre = false;
+                    if !markdown.ends_with('\n') {
+                        markdown.push('\n');
+                    }
+                    markdown.push_str("```\n\n");
+                }
+                ("code", false) if !pre => code = Some(String::new()),
+                ("code", true) if !pre => {
+                    let contents = code.take().unwrap_or_default();
+                    let emphasized = contents.starts_with('\u{1}') && contents.ends_with('\u{2}');
+                    let contents = contents.replace(['\u{1}', '\u{2}'], "");
+                    if emphasized {
+                        markdown.push_str(&format!("*`{}`*", contents));
+                    } else {
+                        markdown.push_str(&format!("`{}`", contents));
+                    }
+                }
+                // emphasis in code is marked with placeholders until the code is closed.
+                ("em", false) if code.is_some() => code.as_mut().unwrap().push('\u{1}'),
+                ("em", true) if code.is_some() => code.as_mut().unwrap().push('\u{2}'),
+                // Markdown code blocks cannot show emphasis, and they have to stay usable as example input.
+                ("em", _) if !pre => markdown.push('*'),
+                ("a", false) => {
+                    links.push(attribute(&captures[3], "href").map_or(String::new(), absolute_url));
+                    markdown.push('[');
+                }
+                ("a", true) => {
+                    let href = links.pop().unwrap_or_default();
+                    markdown.push_str(&format!("]({})", href));
+                }
+                ("li", false) => markdown.push_str("- "),
+                ("li", true) => markdown.push('\n'),
+                ("ul", true) => markdown.push('\n'),
+                _ => (),
+            }
+        }
+        markdown.push_str(decode_entities(&article[last..]).trim());
+        markdown = markdown.trim_end().to_string() + "\n\n";
+    }
+
+    markdown.trim_end().to_string() + "\n"
+}
+
+/// Splits a description into the text of part one and part two.
+fn split_parts(markdown: &str) -> [&str; 2] {
+    match markdown.find(&format!("## {}", PART_TWO_HEADING)) {
+        Some(index) => [&markdown[..index], &markdown[index..]],
+        None => [markdown, ""],
+    }
+}
+
+/// Phrases of the paragraph before an example, e.g. "For example:" or "Here's a larger example:".
+const EXAMPLE_LEAD_INS: [&str; 3] = ["for example", "larger example", "example:"];
+
+/// Code blocks that directly follow a paragraph containing one of the `EXAMPLE_LEAD_INS`.
+fn example_blocks(text: &str) -> Vec<String> {
+    let mut blocks = vec![];
+    let mut chunks = text.split("```\n");
+    while let Some(chunk) = chunks.next() {
+        let Some(block) = chunks.next() else { break };
+        let paragraph = chunk.trim_end().rsplit("\n\n").next().unwrap_or_default();
+        let paragraph = paragraph.to_lowercase();
+        if EXAMPLE_LEAD_INS
+            .iter()
+            .any(|lead_in| paragraph.contains(lead_in))
+        {
+            blocks.push(block.to_string());
+        }
+    }
+    blocks
+}
+
+/// The last highlighted code in a part, which usually is the answer for the example.
+fn highlighted_answer(text: &str) -> Option<String> {
+    let highlighted = Regex::new(r"\*`([^`]+)`\*").unwrap();
+    highlighted
+        .captures_iter(text)
+        .last()
+        .map(|c| c[1].to_string())
+}
+
+/// Extracts the examples of a puzzle description and guesses their expected answers.
+///
+/// The first example of a day is named `NN`. If there are more, they are named `NN-a`, `NN-b`, ...
+/// Each part's answer is assigned to the part's first example.
+/// Part two only falls back to the first example of part one if it has no example of its own.
+pub fn extract_examples(markdown: &str, day: u8) -> Vec<Example> {
+    let parts = split_parts(markdown);
+
+    let mut inputs = vec![];
+    let mut owners = [None, None];
+    for (part, text) in parts.iter().enumerate() {
+        for block in example_blocks(text) {
+            owners[part].get_or_insert(inputs.len());
+            inputs.push(block);
+        }
+    }
+
+    let padded = format!("{:02}", day);
+    let mut examples: Vec<Example> = inputs
+        .into_iter()
+        .map(|input| Example {
+            name: padded.clone(),
+            input,
+            expected: DayAnswers::default(),
+        })
+        .collect();
+    if examples.len() > 1 {
+        for (i, example) in examples.iter_mut().enumerate() {
+            example.name = format!("{}-{}", padded, (b'a' + i as u8) as char);
+        }
+    }
+
+    let answers = parts.map(highlighted_answer);
+    if let Some(example) = owners[0].and_then(|i| examples.get_mut(i)) {
+        example.expected.part_one = answers[0].clone();
+    }
+    if !parts[1].is_empty() {
+        // part two's own example, or the first one of part one if it has none.
+        if let Some(example) = owners[1].or(owners[0]).and_then(|i| examples.get_mut(i)) {
+            example.expected.part_two = answers[1].clone();
+        }
+    }
+    examples
+}
+
+/// Extracts the examples of a description into `src/examples/<year>` and the manifest, see `manifest::save_examples`.
+pub fn save_examples(year: i32, day: u8, markdown: &str) -> io::Result<Vec<Example>> {
+    let examples = extract_examples(markdown, day);
+    if !examples.is_empty() {
+        manifest::save_examples(year, day, &examples)?;
+    }
+    Ok(examples)
+}
+
+/// Prints what `save_examples` extracted.
+pub fn print_examples(year: i32, examples: &[Example]) {
+    for example in examples {
+        println!(
+            "Extracted example \"{}\" (part one: {}, part two: {})",
+            example.name,
+            example.expected(1).unwrap_or("-"),
+            example.expected(2).unwrap_or("-")
+        );
+    }
+    if !examples.is_empty() {
+        println!(
+            "Expected answers are guessed from the highlighted text, check \"{}\".",
+            crate::config::get()
+                .examples_dir(year)
+                .join(manifest::MANIFEST_FILE)
+                .display()
+        );
+    }
+}
+
+/// Renders Markdown produced by `to_markdown` for the terminal.
+pub fn render(markdown: &str) -> String {
+    let links = Regex::new(r"\[([^\]]*)\]\([^)]*\)").unwrap();
+
+    let mut output = String::new();
+    let mut pre = false;
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            pre = !pre;
+            continue;
+        }
+        if pre {
+            output.push_str(&format!("    {}\n", line));
+            continue;
+        }
+        if let Some(heading) = line.strip_prefix("## ") {
+            output.push_str(&format!("{}{}{}\n", ANSI_BOLD, heading, ANSI_RESET));
+            continue;
+        }
+
+        let line = links.replace_all(line, "$1");
+        let (mut em, mut code) = (false, false);
+        for c in line.chars() {
+            match c {
+                '`' => code = !code,
+                '*' if !code => em = !em,
+                c => {
+                    output.push(c);
+                    continue;
+                }
+            }
+            output.push_str(ANSI_RESET);
+            if em {
+                output.push_str(ANSI_BOLD);
+            }
+            if code {
+                output.push_str(ANSI_ITALIC);
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns writing down the number of <em>Calories</em> contained by the various meals (see <a href="/2022/about">about</a>).</p>
+<p>For example:</p>
+<pre><code>1000
+<em>2000</em>
+</code></pre>
+<ul>
+<li>The first Elf is carrying food with <code>1000</code> Calories.</li>
+<li>The total is <code><em>24000</em></code> &amp; not <code>x &lt; y</code>.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>71924</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Find the top <em>three</em> Elves.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_to_markdown_drops_emphasis_in_code_blocks() {
+        assert_eq!(
+            to_markdown(PAGE),
+            "## --- Day 1: Calorie Counting ---\n\n\
+             The Elves take turns writing down the number of *Calories* contained by the various meals (see [about](https://adventofcode.com/2022/about)).\n\n\
+             For example:\n\n\
+             ```\n1000\n2000\n```\n\n\
+             - The first Elf is carrying food with `1000` Calories.\n\
+             - The total is *`24000`* & not `x < y`.\n\n\
+             ## --- Part Two ---\n\n\
+             Find the top *three* Elves.\n"
+        );
+    }
+
+    #[test]
+    fn test_has_part_two() {
+        assert!(has_part_two(&to_markdown(PAGE)));
+        let part_one = PAGE.split("<p>Your puzzle answer").next().unwrap();
+        assert!(!has_part_two(&to_markdown(part_one)));
+    }
+
+    #[test]
+    fn test_extract_examples() {
+        let examples = extract_examples(&to_markdown(PAGE), 1);
+        assert_eq!(
+            examples,
+            vec![Example {
+                name: "01".to_string(),
+                input: "1000\n2000\n".to_string(),
+                expected: DayAnswers {
+                    part_one: Some("24000".to_string()),
+                    part_two: None,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_extract_examples_per_part() {
+        let markdown = "## --- Day 9: Rope Bridge ---\n\n\
+            For example:\n\n```\nR 4\nU 4\n```\n\n\
+            So, there are *`13`* positions.\n\n\
+            ## --- Part Two ---\n\n\
+            Here's a larger example:\n\n```\nR 5\nU 8\n```\n\n\
+            ```\nnot an example\n```\n\n\
+            Now, the tail visits *`36`* positions.\n";
+        let examples = extract_examples(markdown, 9);
+
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].name, "09-a");
+        assert_eq!(examples[0].input, "R 4\nU 4\n");
+        assert_eq!(examples[0].expected(1), Some("13"));
+        assert_eq!(examples[0].expected(2), None);
+        assert_eq!(examples[1].name, "09-b");
+        assert_eq!(examples[1].input, "R 5\nU 8\n");
+        assert_eq!(examples[1].expected(1), None);
+        assert_eq!(examples[1].expected(2), Some("36"));
+
+        let markdown = "Consider this example:\n\n```\nR 4\n```\n\n\
+            It visits *`13`* positions.\n\n\
+            ## --- Part Two ---\n\n\
+            ```\nnot an example\n```\n\n\
+            With ten knots, it visits *`1`* position.\n";
+        let examples = extract_examples(markdown, 9);
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].name, "09");
+        assert_eq!(examples[0].expected(1), Some("13"));
+        assert_eq!(examples[0].expected(2), Some("1"));
+    }
+
+    #[test]
+    fn test_render() {
+        let rendered = render("## Title\n\nA *b* `c` [d](https://e)\n\n```\n1 * 2\n```\n");
+        assert_eq!(
+            rendered,
+            format!(
+                "{b}Title{r}\n\nA {r}{b}b{r} {r}{i}c{r} d\n\n    1 * 2\n",
+                b = ANSI_BOLD,
+                i = ANSI_ITALIC,
+                r = ANSI_RESET
+            )
+        );
+    }
+}
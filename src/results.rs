@@ -0,0 +1,134 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::bench::Stats;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Environment variable naming the file that `solution::solve` appends its JSON-lines records to.
+pub const RESULTS_ENV: &str = "AOC_RESULTS_FILE";
+
+/// `part` of the record that times the shared parse step.
+pub const PARSE_PART: u8 = 0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Solved,
+    Unsolved,
+}
+
+/// Machine-readable outcome of running one part of a day.
+/// In bench mode, `duration_ns` is the median of all runs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PartResult {
+    pub year: i32,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration_ns: u64,
+    pub status: Status,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bench: Option<Stats>,
+}
+
+/// Human-readable name of a record's `part`.
+pub fn part_name(part: u8) -> String {
+    if part == PARSE_PART {
+        "parse".to_string()
+    } else {
+        format!("part {}", part)
+    }
+}
+
+impl PartResult {
+    /// Appends this record to the file named by `AOC_RESULTS_FILE`, if set.
+    pub fn emit(&self) -> io::Result<()> {
+        let Some(path) = env::var_os(RESULTS_ENV) else {
+            return Ok(());
+        };
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        let line = serde_json::to_string(self).map_err(io::Error::from)?;
+        writeln!(file, "{}", line)
+    }
+}
+
+pub fn parse_results(output: &str) -> Result<Vec<PartResult>, serde_json::Error> {
+    output
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(serde_json::from_str)
+        .collect()
+}
+
+pub fn read_results(path: &Path) -> io::Result<Vec<PartResult>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+    parse_results(&contents).map_err(io::Error::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_results() {
+        let output = concat!(
+            r#"{"year":2022,"day":1,"part":1,"answer":"24000","duration_ns":74,"status":"solved"}"#,
+            "\n",
+            r#"{"year":2022,"day":1,"part":2,"answer":null,"duration_ns":12,"status":"unsolved"}"#,
+            "\n"
+        );
+        assert_eq!(
+            parse_results(output).unwrap(),
+            vec![
+                PartResult {
+                    year: 2022,
+                    day: 1,
+                    part: 1,
+                    answer: Some("24000".into()),
+                    duration_ns: 74,
+                    status: Status::Solved,
+                    bench: None,
+                },
+                PartResult {
+                    year: 2022,
+                    day: 1,
+                    part: 2,
+                    answer: None,
+                    duration_ns: 12,
+                    status: Status::Unsolved,
+                    bench: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_roundtrip_escapes_answer() {
+        let result = PartResult {
+            year: 2022,
+            day: 10,
+            part: 2,
+            answer: Some("elapsed: \"#..#\"\n".into()),
+            duration_ns: 1_450_000,
+            status: Status::Solved,
+            bench: Some(Stats {
+                iterations: 3,
+                min_ns: 1_400_000,
+                median_ns: 1_450_000,
+                mean_ns: 1_450_000.0,
+                stddev_ns: 40_824.8,
+            }),
+        };
+        let line = serde_json::to_string(&result).unwrap();
+        assert_eq!(parse_results(&line).unwrap(), vec![result]);
+    }
+}
@@ -0,0 +1,444 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::config::YEAR_ENV;
+use crate::days;
+use crate::results::{self, PartResult, RESULTS_ENV};
+use std::collections::BTreeMap;
+use std::env::temp_dir;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::{self, Child, Command, ExitStatus, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Exit code of `solve` when the input file for a day does not exist.
+pub const EXIT_NO_INPUT: i32 = 2;
+
+/// Default wall-clock time a day may run for.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Resource limits applied to every day binary.
+/// Memory and CPU time limits are only enforced on Linux.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    pub memory_mb: Option<u64>,
+    pub cpu_secs: Option<u64>,
+}
+
+/// How a day binary terminated.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    Completed,
+    NoInput,
+    Timeout,
+    Panicked(String),
+    OutOfMemory,
+    Crashed(String),
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Outcome::Completed | Outcome::NoInput)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Completed => write!(f, "completed"),
+            Outcome::NoInput => write!(f, "not solved"),
+            Outcome::Timeout => write!(f, "timeout"),
+            Outcome::Panicked(message) => write!(f, "panicked: {}", message),
+            Outcome::OutOfMemory => write!(f, "OOM"),
+            Outcome::Crashed(reason) => write!(f, "crashed: {}", reason),
+        }
+    }
+}
+
+/// Output of running a single day binary.
+pub struct DayRun {
+    pub stdout: String,
+    pub records: Vec<PartResult>,
+    pub outcome: Outcome,
+}
+
+/// Days of `years` that have a registered solution, ordered by year and day.
+pub fn available_days(years: &[i32]) -> Vec<(i32, u8)> {
+    let mut days: Vec<(i32, u8)> = days::YEARS
+        .iter()
+        .filter(|y| years.contains(&y.year))
+        .flat_map(|y| y.days)
+        .map(|d| (d.year, d.day))
+        .collect();
+    days.sort_unstable();
+    days
+}
+
+/// Parses a day filter such as `7`, `3..7`, `3..=7`, `20..` or `..=5`.
+pub fn parse_days(s: &str) -> Result<RangeInclusive<u8>, String> {
+    let invalid = || format!("invalid day range: \"{}\"", s);
+    let bound = |b: &str, default: u8| -> Result<u8, String> {
+        if b.is_empty() {
+            Ok(default)
+        } else {
+            b.parse().map_err(|_| invalid())
+        }
+    };
+
+    let Some((start, end)) = s.split_once("..") else {
+        let day = bound(s, 0)?;
+        return Ok(day..=day);
+    };
+    let start = bound(start, 1)?;
+    let end = match end.strip_prefix('=') {
+        Some(end) => bound(end, 25)?,
+        None if end.is_empty() => 25,
+        None => bound(end, 0)?.checked_sub(1).ok_or_else(invalid)?,
+    };
+    Ok(start..=end)
+}
+
+/// Builds the release `solve` binary once and returns its path.
+pub fn build() -> io::Result<PathBuf> {
+    build_bin("solve", true)
+}
+
+/// Builds a binary of this package and returns its path.
+pub fn build_bin(name: &str, release: bool) -> io::Result<PathBuf> {
+    let mut cmd = Command::new("cargo");
+    cmd.args([
+        "build",
+        "--bin",
+        name,
+        "--message-format=json-render-diagnostics",
+    ]);
+    if release {
+        cmd.arg("--release");
+    }
+    let output = cmd.stderr(Stdio::inherit()).output()?;
+    if !output.status.success() {
+        return Err(io::Error::other("cargo build failed"));
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|l| serde_json::from_str::<serde_json::Value>(l).ok())
+        .filter(|m| m["reason"] == "compiler-artifact" && m["target"]["name"] == name)
+        .find_map(|m| m["executable"].as_str().map(PathBuf::from))
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} binary not found", name),
+            )
+        })
+}
+
+/// Extracts the message from a panic printed by the default panic hook.
+pub fn panic_message(stderr: &str) -> Option<String> {
+    let mut lines = stderr.lines();
+    let line = lines.find(|l| l.contains("panicked at "))?;
+    let (_, location) = line.split_once("panicked at ")?;
+
+    // before Rust 1.73 the message was printed inline: `panicked at 'message', src/..`
+    if let Some(rest) = location.strip_prefix('\'') {
+        let end = rest.rfind("', ").unwrap_or(rest.len());
+        return Some(rest[..end].to_string());
+    }
+    let message: Vec<&str> = lines
+        .take_while(|l| !l.starts_with("note: ") && !l.starts_with("stack backtrace:"))
+        .collect();
+    Some(message.join("\n"))
+}
+
+fn is_out_of_memory(stderr: &str) -> bool {
+    stderr
+        .lines()
+        .any(|l| l.starts_with("memory allocation of ") && l.ends_with(" failed"))
+}
+
+#[cfg(unix)]
+fn signal(status: &ExitStatus) -> Option<i32> {
+    use std::os::unix::process::ExitStatusExt;
+    status.signal()
+}
+
+#[cfg(not(unix))]
+fn signal(_status: &ExitStatus) -> Option<i32> {
+    None
+}
+
+/// Classifies how a day binary terminated from its exit status and stderr.
+pub fn classify(status: &ExitStatus, stderr: &str, timed_out: bool) -> Outcome {
+    const SIGKILL: i32 = 9;
+    const SIGXCPU: i32 = 24;
+
+    if timed_out {
+        return Outcome::Timeout;
+    }
+    if status.success() {
+        return Outcome::Completed;
+    }
+    if is_out_of_memory(stderr) {
+        return Outcome::OutOfMemory;
+    }
+    if let Some(message) = panic_message(stderr) {
+        return Outcome::Panicked(message);
+    }
+    match (status.code(), signal(status)) {
+        (Some(EXIT_NO_INPUT), _) => Outcome::NoInput,
+        // the CPU time limit was exceeded.
+        (_, Some(SIGXCPU)) => Outcome::Timeout,
+        // nobody but the kernel's OOM killer sends SIGKILL to a day binary.
+        (_, Some(SIGKILL)) => Outcome::OutOfMemory,
+        (Some(code), _) => Outcome::Crashed(format!("exit status {}", code)),
+        (None, Some(signal)) => Outcome::Crashed(format!("signal {}", signal)),
+        (None, None) => Outcome::Crashed(status.to_string()),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn apply_limits(cmd: &mut Command, limits: &Limits) {
+    use std::os::unix::process::CommandExt;
+
+    let memory = limits.memory_mb.map(|mb| mb * 1024 * 1024);
+    let cpu = limits.cpu_secs;
+    if memory.is_none() && cpu.is_none() {
+        return;
+    }
+
+    let set = |resource, soft: u64, hard: u64| {
+        let limit = libc::rlimit {
+            rlim_cur: soft as libc::rlim_t,
+            rlim_max: hard as libc::rlim_t,
+        };
+        // SAFETY: `setrlimit` is async-signal-safe and only reads `limit`.
+        if unsafe { libc::setrlimit(resource, &limit) } == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    };
+
+    // SAFETY: the closure only calls `setrlimit` and does not allocate.
+    unsafe {
+        cmd.pre_exec(move || {
+            if let Some(bytes) = memory {
+                set(libc::RLIMIT_AS, bytes, bytes)?;
+            }
+            if let Some(secs) = cpu {
+                // the soft limit sends SIGXCPU, the hard limit would send SIGKILL.
+                set(libc::RLIMIT_CPU, secs, secs + 1)?;
+            }
+            Ok(())
+        });
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn apply_limits(_cmd: &mut Command, _limits: &Limits) {}
+
+/// Reads a child's pipe to the end on a separate thread, so a full pipe can never block the child.
+fn drain(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut output = vec![];
+        if let Some(mut pipe) = pipe {
+            #[allow(unused_must_use)]
+            {
+                pipe.read_to_end(&mut output);
+            }
+        }
+        String::from_utf8_lossy(&output).into_owned()
+    })
+}
+
+/// Waits for `child` to exit, killing it once `timeout` has passed.
+/// Returns the exit status and whether the child was killed.
+fn wait_timeout(child: &mut Child, timeout: Option<Duration>) -> io::Result<(ExitStatus, bool)> {
+    let Some(timeout) = timeout else {
+        return Ok((child.wait()?, false));
+    };
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok((status, false));
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            return Ok((child.wait()?, true));
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Runs `solve <day>` for `year` within `limits` and collects its result records.
+/// The year is passed through `AOC_YEAR`, which overrides the configured year.
+pub fn run_day(exe: &Path, year: i32, day: u8, envs: &[(&str, String)], limits: &Limits) -> DayRun {
+    let day = format!("{:02}", day);
+
+    // solutions report their results through a side channel, see `results.rs`.
+    let results_path = temp_dir().join(format!("aoc_results_{}_{}_{}", process::id(), year, day));
+    #[allow(unused_must_use)]
+    {
+        fs::remove_file(&results_path);
+    }
+
+    let mut cmd = Command::new(exe);
+    cmd.arg(&day)
+        .env(YEAR_ENV, year.to_string())
+        .env(RESULTS_ENV, &results_path)
+        .envs(envs.iter().map(|(k, v)| (k, v)))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    apply_limits(&mut cmd, limits);
+
+    let (stdout, outcome) = match cmd.spawn() {
+        Ok(mut child) => {
+            let stdout = drain(child.stdout.take());
+            let stderr = drain(child.stderr.take());
+            let waited = wait_timeout(&mut child, limits.timeout);
+            let (stdout, stderr) = (stdout.join().unwrap(), stderr.join().unwrap());
+            let outcome = match waited {
+                Ok((status, timed_out)) => classify(&status, &stderr, timed_out),
+                Err(e) => Outcome::Crashed(e.to_string()),
+            };
+            (stdout, outcome)
+        }
+        Err(e) => (String::new(), Outcome::Crashed(e.to_string())),
+    };
+
+    let records = results::read_results(&results_path).unwrap_or_else(|e| {
+        eprintln!("could not read results for day {} of {}: {}", day, year, e);
+        vec![]
+    });
+    #[allow(unused_must_use)]
+    {
+        fs::remove_file(&results_path);
+    }
+
+    DayRun {
+        stdout,
+        records,
+        outcome,
+    }
+}
+
+/// Iterator over the runs of several days, yielded in order of year and day.
+pub struct Runs {
+    receiver: Receiver<((i32, u8), DayRun)>,
+    pending: BTreeMap<(i32, u8), DayRun>,
+    order: std::vec::IntoIter<(i32, u8)>,
+}
+
+impl Iterator for Runs {
+    type Item = ((i32, u8), DayRun);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let day = self.order.next()?;
+        while !self.pending.contains_key(&day) {
+            let (finished, run) = self.receiver.recv().ok()?;
+            self.pending.insert(finished, run);
+        }
+        Some((day, self.pending.remove(&day).unwrap()))
+    }
+}
+
+/// Runs `days`, given as year and day, on up to `jobs` threads.
+/// Results are yielded in order as soon as they are available.
+pub fn run_days(
+    exe: &Path,
+    days: Vec<(i32, u8)>,
+    jobs: usize,
+    envs: Vec<(&'static str, String)>,
+    limits: Limits,
+) -> Runs {
+    let queue = Arc::new(Mutex::new(days.clone().into_iter()));
+    let (sender, receiver) = mpsc::channel();
+
+    for _ in 0..jobs.max(1).min(days.len()) {
+        let queue = Arc::clone(&queue);
+        let sender = sender.clone();
+        let exe = exe.to_path_buf();
+        let envs = envs.clone();
+        thread::spawn(move || loop {
+            let Some((year, day)) = queue.lock().unwrap().next() else { break };
+            if sender
+                .send(((year, day), run_day(&exe, year, day, &envs, &limits)))
+                .is_err()
+            {
+                break;
+            }
+        });
+    }
+
+    Runs {
+        receiver,
+        pending: BTreeMap::new(),
+        order: days.into_iter(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("7"), Ok(7..=7));
+        assert_eq!(parse_days("3..=7"), Ok(3..=7));
+        assert_eq!(parse_days("3..7"), Ok(3..=6));
+        assert_eq!(parse_days("20.."), Ok(20..=25));
+        assert_eq!(parse_days("..=5"), Ok(1..=5));
+        assert!(parse_days("..0").is_err());
+        assert!(parse_days("three").is_err());
+    }
+
+    #[test]
+    fn test_panic_message() {
+        let stderr = "thread 'main' panicked at src/days/day05.rs:12:5:\nattempt to subtract with overflow\nnote: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n";
+        assert_eq!(
+            panic_message(stderr).as_deref(),
+            Some("attempt to subtract with overflow")
+        );
+
+        let legacy = "thread 'main' panicked at 'no solution', src/days/day05.rs:12:5\n";
+        assert_eq!(panic_message(legacy).as_deref(), Some("no solution"));
+
+        assert_eq!(panic_message("some other output"), None);
+    }
+
+    #[test]
+    fn test_is_out_of_memory() {
+        assert!(is_out_of_memory(
+            "memory allocation of 4294967296 bytes failed\n"
+        ));
+        assert!(!is_out_of_memory(
+            "thread 'main' panicked at src/main.rs:1:1:\noops\n"
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_day_timeout() {
+        // `sleep 05` stands in for a day binary that never finishes.
+        let limits = Limits {
+            timeout: Some(Duration::from_millis(100)),
+            ..Limits::default()
+        };
+        let start = Instant::now();
+        let run = run_day(Path::new("sleep"), 2022, 5, &[], &limits);
+        assert_eq!(run.outcome, Outcome::Timeout);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+}
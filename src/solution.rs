@@ -0,0 +1,191 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::{self, Verdict};
+use crate::bench::{self, Stats};
+use crate::results::{PartResult, Status, PARSE_PART};
+use crate::{Input, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+/// A solution for a single day.
+pub trait Solution {
+    const YEAR: i32;
+    const DAY: u8;
+
+    type Parsed;
+    type AnswerOne: Display;
+    type AnswerTwo: Display;
+
+    fn parse(input: &Input) -> Self::Parsed;
+    fn part_one(parsed: &Self::Parsed) -> Option<Self::AnswerOne>;
+    fn part_two(parsed: &Self::Parsed) -> Option<Self::AnswerTwo>;
+}
+
+/// Controls which parts `solve` runs.
+#[derive(Clone, Copy, Debug)]
+pub struct Options {
+    /// Only run this part, or both parts if `None`.
+    pub part: Option<u8>,
+    /// Compare answers to the lockfile. Only makes sense for the puzzle input.
+    pub check_answers: bool,
+    /// Time budget per part to benchmark it, see `bench::budget`. Parts run once if `None`.
+    pub bench: Option<Duration>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            part: None,
+            check_answers: true,
+            bench: None,
+        }
+    }
+}
+
+/// Type-erased registry entry for a `Solution`.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: i32,
+    pub day: u8,
+    pub solve: fn(&Input, &Options),
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            solve: solve::<S>,
+        }
+    }
+}
+
+/// Registry entry for the solutions of a year.
+#[derive(Clone, Copy)]
+pub struct Year {
+    pub year: i32,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub const fn new(year: i32, days: &'static [Day]) -> Self {
+        Year { year, days }
+    }
+}
+
+/// Times `func`, or benchmarks it if there is a budget.
+fn measure<T>(bench: Option<Duration>, func: impl Fn() -> T) -> (T, Duration, Option<Stats>) {
+    match bench {
+        Some(budget) => {
+            let (result, stats) = bench::run(func, budget);
+            (result, Duration::from_nanos(stats.median_ns), Some(stats))
+        }
+        None => {
+            let timer = Instant::now();
+            let result = func();
+            (result, timer.elapsed(), None)
+        }
+    }
+}
+
+fn format_timing(elapsed: Duration, stats: &Option<Stats>) -> String {
+    match stats {
+        Some(stats) => format!(
+            "{}(median: {:.2?}, min: {:.2?}, mean: {:.2?}, σ: {:.2?}, {} runs){}",
+            ANSI_ITALIC,
+            elapsed,
+            Duration::from_nanos(stats.min_ns),
+            Duration::from_nanos(stats.mean_ns as u64),
+            Duration::from_nanos(stats.stddev_ns as u64),
+            stats.iterations,
+            ANSI_RESET
+        ),
+        None => format!("{}(elapsed: {:.2?}){}", ANSI_ITALIC, elapsed, ANSI_RESET),
+    }
+}
+
+fn emit_record(
+    year: i32,
+    day: u8,
+    part: u8,
+    status: Status,
+    answer: Option<String>,
+    elapsed: Duration,
+    stats: Option<Stats>,
+) {
+    let record = PartResult {
+        year,
+        day,
+        part,
+        status,
+        answer,
+        duration_ns: elapsed.as_nanos() as u64,
+        bench: stats,
+    };
+    if let Err(e) = record.emit() {
+        eprintln!("could not write result record: {}", e);
+    }
+}
+
+fn print_parse<P>(year: i32, day: u8, bench: Option<Duration>, func: impl Fn() -> P) -> P {
+    let (parsed, elapsed, stats) = measure(bench, func);
+    println!("{}", format_timing(elapsed, &stats));
+    emit_record(year, day, PARSE_PART, Status::Solved, None, elapsed, stats);
+    parsed
+}
+
+fn print_result<T: Display>(
+    year: i32,
+    day: u8,
+    part: u8,
+    options: &Options,
+    func: impl Fn() -> Option<T>,
+) {
+    let (result, elapsed, stats) = measure(options.bench, func);
+    let answer = result.map(|r| r.to_string());
+
+    // mark the answer if it has been accepted into the lockfile before.
+    let locked = answers::locked(year, day, part).filter(|_| options.check_answers);
+    let mark = match Verdict::new(locked.as_deref(), answer.as_deref()) {
+        Verdict::Pass => " ✓".to_string(),
+        Verdict::Fail | Verdict::Changed => {
+            format!(" ✗ (expected: {})", locked.unwrap_or_default())
+        }
+        Verdict::Missing => String::new(),
+    };
+
+    match &answer {
+        Some(answer) => {
+            println!("{}{} {}", answer, mark, format_timing(elapsed, &stats));
+        }
+        None => {
+            println!("not solved.{}", mark)
+        }
+    }
+
+    let status = if answer.is_some() {
+        Status::Solved
+    } else {
+        Status::Unsolved
+    };
+    emit_record(year, day, part, status, answer, elapsed, stats);
+}
+
+/// Parses the input once, then runs the selected parts on the parsed input.
+/// Parsing, part one and part two are timed separately.
+pub fn solve<S: Solution>(input: &Input, options: &Options) {
+    let runs = |part| options.part.is_none_or(|p| p == part);
+
+    println!("🎄 {}Parse{} 🎄", ANSI_BOLD, ANSI_RESET);
+    let parsed = print_parse(S::YEAR, S::DAY, options.bench, || S::parse(input));
+    if runs(1) {
+        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, 1, ANSI_RESET);
+        print_result(S::YEAR, S::DAY, 1, options, || S::part_one(&parsed));
+    }
+    if runs(2) {
+        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, 2, ANSI_RESET);
+        print_result(S::YEAR, S::DAY, 2, options, || S::part_two(&parsed));
+    }
+}
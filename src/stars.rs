@@ -0,0 +1,279 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::Answers;
+use crate::history::Run;
+use crate::submit::{Attempt, Outcome};
+use regex::Regex;
+use std::time::Duration;
+
+pub const README_FILE: &str = "README.md";
+
+/// Line of the readme after which the progress table is written. Shared with the Github action.
+pub const MARKER: &str = "<!--- advent_readme_stars table --->";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PartProgress {
+    pub solved: bool,
+    pub answer: Option<String>,
+    pub duration_ns: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayProgress {
+    pub day: u8,
+    pub parts: [PartProgress; 2],
+    /// Whether the day has a registered solution.
+    pub registered: bool,
+}
+
+impl DayProgress {
+    pub fn stars(&self) -> usize {
+        self.parts.iter().filter(|p| p.solved).count()
+    }
+}
+
+fn part_progress(
+    year: i32,
+    day: u8,
+    part: u8,
+    answers: &Answers,
+    attempts: &[Attempt],
+    history: &[Run],
+) -> PartProgress {
+    let attempts: Vec<&Attempt> = attempts
+        .iter()
+        .filter(|a| a.year == year && a.day == day && a.part == part)
+        .collect();
+    let correct = attempts
+        .iter()
+        .rev()
+        .find(|a| a.outcome == Outcome::Correct)
+        .map(|a| a.answer.clone());
+    let answer = answers.get(year, day, part).map(str::to_string).or(correct);
+    let solved = answer.is_some() || attempts.iter().any(|a| a.outcome == Outcome::AlreadySolved);
+
+    // the most recent timing, from the last run that included the part.
+    let duration_ns = history
+        .iter()
+        .rev()
+        .flat_map(|r| &r.timings)
+        .find(|t| t.year == year && t.day == day && t.part == part)
+        .map(|t| t.duration_ns);
+
+    PartProgress {
+        solved,
+        answer,
+        duration_ns,
+    }
+}
+
+/// Progress of every day of `year` that is registered or has a star, in order.
+///
+/// A part is solved if it has an accepted answer in the lockfile, or the submission history says so.
+pub fn progress(
+    year: i32,
+    registered: &[u8],
+    answers: &Answers,
+    attempts: &[Attempt],
+    history: &[Run],
+) -> Vec<DayProgress> {
+    (1..=25)
+        .map(|day| DayProgress {
+            day,
+            parts: [1, 2].map(|part| part_progress(year, day, part, answers, attempts, history)),
+            registered: registered.contains(&day),
+        })
+        .filter(|d| d.registered || d.stars() > 0)
+        .collect()
+}
+
+/// Renders the progress of a year as a Markdown section.
+/// `solutions` is the path of the year's solution modules, relative to the readme.
+pub fn render_section(year: i32, days: &[DayProgress], solutions: &str) -> String {
+    let stars: usize = days.iter().map(DayProgress::stars).sum();
+    let mut lines = vec![
+        format!("## {} Results ({} ⭐)", year, stars),
+        String::new(),
+        "| Day | Part 1 | Part 2 | Answer 1 | Answer 2 | Time 1 | Time 2 | Solution |".to_string(),
+        "| :---: | :---: | :---: | :---: | :---: | ---: | ---: | :--- |".to_string(),
+    ];
+    for day in days {
+        let [one, two] = &day.parts;
+        let star = |p: &PartProgress| if p.solved { "⭐" } else { "" };
+        let answer = |p: &PartProgress| match &p.answer {
+            Some(answer) => format!("`{}`", answer.replace('|', "\\|")),
+            None => "-".to_string(),
+        };
+        let time = |p: &PartProgress| match p.duration_ns {
+            Some(ns) => format!("{:.2?}", Duration::from_nanos(ns)),
+            None => "-".to_string(),
+        };
+        let solution = if day.registered {
+            let file = format!("day{:02}.rs", day.day);
+            format!("[{}]({}/{})", file, solutions, file)
+        } else {
+            "-".to_string()
+        };
+        lines.push(format!(
+            "| [Day {}](https://adventofcode.com/{}/day/{}) | {} | {} | {} | {} | {} | {} | {} |",
+            day.day,
+            year,
+            day.day,
+            star(one),
+            star(two),
+            answer(one),
+            answer(two),
+            time(one),
+            time(two),
+            solution
+        ));
+    }
+    lines.join("\n")
+}
+
+/// Replaces the sections after the marker line with `sections`.
+/// Everything up to the first line that is not part of a previously written section is kept.
+///
+/// Returns `None` if the readme has no marker.
+pub fn update_readme(readme: &str, sections: &[String]) -> Option<String> {
+    let lines: Vec<&str> = readme.lines().collect();
+    let marker = lines.iter().position(|l| l.trim() == MARKER)?;
+    let heading = Regex::new(r"^## \d+ Results").unwrap();
+    let end = lines[marker + 1..]
+        .iter()
+        .position(|l| !(l.trim().is_empty() || l.starts_with('|') || heading.is_match(l)))
+        .map_or(lines.len(), |i| marker + 1 + i);
+
+    let mut updated: Vec<String> = lines[..=marker].iter().map(|l| l.to_string()).collect();
+    for section in sections {
+        updated.push(section.clone());
+        updated.push(String::new());
+    }
+    updated.extend(lines[end..].iter().map(|l| l.to_string()));
+    let trailing = if readme.ends_with('\n') { "\n" } else { "" };
+    Some(updated.join("\n") + trailing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::Timing;
+
+    fn attempt(day: u8, part: u8, answer: &str, outcome: Outcome) -> Attempt {
+        Attempt {
+            year: 2022,
+            day,
+            part,
+            answer: answer.to_string(),
+            date: "2022-12-01T05:10:00Z".into(),
+            outcome,
+        }
+    }
+
+    fn run(timings: &[(u8, u8, u64)]) -> Run {
+        Run {
+            commit: None,
+            date: "2022-12-07T05:00:00Z".into(),
+            bench: false,
+            timings: timings
+                .iter()
+                .map(|&(day, part, duration_ns)| Timing {
+                    year: 2022,
+                    day,
+                    part,
+                    duration_ns,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_progress() {
+        let mut answers = Answers::default();
+        answers.set(2022, 1, 1, "24000".into());
+        answers.set(2021, 3, 1, "198".into());
+        let attempts = vec![
+            attempt(1, 2, "1", Outcome::TooLow),
+            attempt(1, 2, "45000", Outcome::Correct),
+            attempt(4, 1, "2", Outcome::AlreadySolved),
+            attempt(5, 1, "CMZ", Outcome::Incorrect),
+        ];
+        let history = vec![run(&[(1, 1, 100), (1, 2, 200)]), run(&[(1, 1, 150)])];
+
+        let progress = progress(2022, &[1, 2], &answers, &attempts, &history);
+        let days: Vec<(u8, usize)> = progress.iter().map(|d| (d.day, d.stars())).collect();
+        assert_eq!(days, [(1, 2), (2, 0), (4, 1)]);
+        assert_eq!(
+            progress[0].parts,
+            [
+                PartProgress {
+                    solved: true,
+                    answer: Some("24000".into()),
+                    duration_ns: Some(150),
+                },
+                PartProgress {
+                    solved: true,
+                    answer: Some("45000".into()),
+                    duration_ns: Some(200),
+                },
+            ]
+        );
+        assert_eq!(progress[2].parts[0].answer, None);
+        assert!(!progress[2].registered);
+    }
+
+    #[test]
+    fn test_render_section() {
+        let days = vec![
+            DayProgress {
+                day: 1,
+                parts: [
+                    PartProgress {
+                        solved: true,
+                        answer: Some("24000".into()),
+                        duration_ns: Some(12_300),
+                    },
+                    PartProgress::default(),
+                ],
+                registered: true,
+            },
+            DayProgress {
+                day: 4,
+                parts: [
+                    PartProgress {
+                        solved: true,
+                        ..PartProgress::default()
+                    },
+                    PartProgress::default(),
+                ],
+                registered: false,
+            },
+        ];
+        assert_eq!(
+            render_section(2022, &days, "src/days/y2022"),
+            "## 2022 Results (2 ⭐)
+
+| Day | Part 1 | Part 2 | Answer 1 | Answer 2 | Time 1 | Time 2 | Solution |
+| :---: | :---: | :---: | :---: | :---: | ---: | ---: | :--- |
+| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ |  | `24000` | - | 12.30µs | - | [day01.rs](src/days/y2022/day01.rs) |
+| [Day 4](https://adventofcode.com/2022/day/4) | ⭐ |  | - | - | - | - | - |"
+        );
+    }
+
+    #[test]
+    fn test_update_readme() {
+        let readme =
+            "# Advent of Code\n\n<!--- advent_readme_stars table --->\n\n---\n\n## Usage\n";
+        let sections = vec!["## 2022 Results (1 ⭐)\n\n| Day |\n| :---: |\n| 1 |".to_string()];
+        let updated = update_readme(readme, &sections).unwrap();
+        assert_eq!(
+            updated,
+            "# Advent of Code\n\n<!--- advent_readme_stars table --->\n## 2022 Results (1 ⭐)\n\n| Day |\n| :---: |\n| 1 |\n\n---\n\n## Usage\n"
+        );
+        // updating again replaces the previous section.
+        assert_eq!(update_readme(&updated, &sections).unwrap(), updated);
+        assert_eq!(update_readme("# Advent of Code\n", &[]), None);
+    }
+}
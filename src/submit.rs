@@ -0,0 +1,356 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::aoc::{self, Client};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+
+/// Every submitted answer, relative to the project root.
+pub const SUBMISSIONS_FILE: &str = "submissions.jsonl";
+
+/// The verdict of the website on a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "result")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Incorrect,
+    /// An answer was submitted too recently.
+    Wait {
+        seconds: u64,
+    },
+    AlreadySolved,
+    /// The response could not be understood, contains the response text.
+    Unknown {
+        message: String,
+    },
+}
+
+impl Outcome {
+    /// Whether the answer was checked and found to be wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect
+        )
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::Wait { seconds } => {
+                write!(f, "wait {}", format_wait(Duration::from_secs(*seconds)))
+            }
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unknown { message } => write!(f, "unknown response: {}", message),
+        }
+    }
+}
+
+fn format_wait(wait: Duration) -> String {
+    match (wait.as_secs() / 60, wait.as_secs() % 60) {
+        (0, s) => format!("{}s", s),
+        (m, 0) => format!("{}m", m),
+        (m, s) => format!("{}m {}s", m, s),
+    }
+}
+
+/// Text of the `<article>` in a response page, with tags removed and whitespace collapsed.
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    let text = tags.replace_all(article, "");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses the response page to a submitted answer.
+pub fn parse_response(html: &str) -> Outcome {
+    let text = article_text(html);
+
+    if text.contains("That's the right answer") {
+        return Outcome::Correct;
+    }
+    if text.contains("You don't seem to be solving the right level") {
+        return Outcome::AlreadySolved;
+    }
+    if text.contains("You gave an answer too recently") {
+        let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+        let seconds = wait.captures(&text).map_or(60, |c| {
+            let minutes: u64 = c.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+            let seconds: u64 = c[2].parse().unwrap();
+            minutes * 60 + seconds
+        });
+        return Outcome::Wait { seconds };
+    }
+    if text.contains("That's not the right answer") {
+        return if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Incorrect
+        };
+    }
+    Outcome::Unknown { message: text }
+}
+
+/// A submitted answer and the website's verdict on it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: i32,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub date: String,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+}
+
+impl Attempt {
+    pub fn new(year: i32, day: u8, part: u8, answer: &str, outcome: Outcome) -> Self {
+        Attempt {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            date: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            outcome,
+        }
+    }
+}
+
+pub fn read_attempts(path: &Path) -> io::Result<Vec<Attempt>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+    contents
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| serde_json::from_str(l).map_err(io::Error::from))
+        .collect()
+}
+
+pub fn append_attempt(path: &Path, attempt: &Attempt) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let line = serde_json::to_string(attempt).map_err(io::Error::from)?;
+    writeln!(file, "{}", line)
+}
+
+/// Why an answer is not submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved,
+    /// The same answer was submitted before and was wrong.
+    Repeated(Outcome),
+    /// A smaller answer was too high before.
+    AboveTooHigh(String),
+    /// A larger answer was too low before.
+    BelowTooLow(String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved => write!(f, "this part has already been solved"),
+            Refusal::Repeated(outcome) => {
+                write!(f, "this answer was submitted before and was {}", outcome)
+            }
+            Refusal::AboveTooHigh(answer) => {
+                write!(f, "the answer \"{}\" was already too high", answer)
+            }
+            Refusal::BelowTooLow(answer) => {
+                write!(f, "the answer \"{}\" was already too low", answer)
+            }
+        }
+    }
+}
+
+/// Checks an answer against previous attempts, so known wrong answers are never submitted.
+pub fn check_attempt(
+    attempts: &[Attempt],
+    year: i32,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<(), Refusal> {
+    let previous: Vec<&Attempt> = attempts
+        .iter()
+        .filter(|a| a.year == year && a.day == day && a.part == part)
+        .collect();
+    if previous
+        .iter()
+        .any(|a| matches!(a.outcome, Outcome::Correct | Outcome::AlreadySolved))
+    {
+        return Err(Refusal::AlreadySolved);
+    }
+
+    let value = answer.parse::<i64>().ok();
+    for attempt in previous {
+        if attempt.answer == answer && attempt.outcome.is_wrong() {
+            return Err(Refusal::Repeated(attempt.outcome.clone()));
+        }
+        let (Some(value), Ok(previous)) = (value, attempt.answer.parse::<i64>()) else {
+            continue;
+        };
+        match attempt.outcome {
+            Outcome::TooHigh if value >= previous => {
+                return Err(Refusal::AboveTooHigh(attempt.answer.clone()))
+            }
+            Outcome::TooLow if value <= previous => {
+                return Err(Refusal::BelowTooLow(attempt.answer.clone()))
+            }
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+/// Posts an answer and parses the response.
+pub fn submit(
+    client: &Client,
+    year: i32,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome, aoc::Error> {
+    let path = format!("/{}/day/{}/answer", year, day);
+    let html = client.post(&path, &[("level", &part.to_string()), ("answer", answer)])?;
+    Ok(parse_response(&html))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::mock;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(&page("That's the right answer!  You are <em>one gold star</em> closer to saving your vacation.")),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2022/about\">about page</a>. Please wait one minute before trying again.")),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer.  If you're stuck, make sure you're using the full input data.")),
+            Outcome::Incorrect
+        );
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait. <a href=\"/2022/day/1\">[Return to Day 1]</a>")),
+            Outcome::Wait { seconds: 272 }
+        );
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 9s left to wait.")),
+            Outcome::Wait { seconds: 9 }
+        );
+        assert_eq!(
+            parse_response(&page("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2022/day/1\">[Return to Day 1]</a>")),
+            Outcome::AlreadySolved
+        );
+        assert_eq!(
+            parse_response(&page("Something <b>new</b>.")),
+            Outcome::Unknown {
+                message: "Something new.".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_check_attempt() {
+        let attempts = vec![
+            Attempt::new(2022, 1, 1, "500", Outcome::TooHigh),
+            Attempt::new(2022, 1, 1, "100", Outcome::TooLow),
+            Attempt::new(2022, 1, 1, "abc", Outcome::Incorrect),
+            Attempt::new(2022, 1, 1, "300", Outcome::Wait { seconds: 30 }),
+            Attempt::new(2022, 2, 1, "7", Outcome::Correct),
+            Attempt::new(2022, 3, 2, "9", Outcome::AlreadySolved),
+        ];
+        assert_eq!(check_attempt(&attempts, 2022, 1, 1, "300"), Ok(()));
+        assert_eq!(
+            check_attempt(&attempts, 2022, 1, 1, "abc"),
+            Err(Refusal::Repeated(Outcome::Incorrect))
+        );
+        assert_eq!(
+            check_attempt(&attempts, 2022, 1, 1, "600"),
+            Err(Refusal::AboveTooHigh("500".to_string()))
+        );
+        assert_eq!(
+            check_attempt(&attempts, 2022, 1, 1, "50"),
+            Err(Refusal::BelowTooLow("100".to_string()))
+        );
+        assert_eq!(
+            check_attempt(&attempts, 2022, 1, 1, "100"),
+            Err(Refusal::Repeated(Outcome::TooLow))
+        );
+        assert_eq!(
+            check_attempt(&attempts, 2022, 2, 1, "8"),
+            Err(Refusal::AlreadySolved)
+        );
+        assert_eq!(
+            check_attempt(&attempts, 2022, 3, 2, "10"),
+            Err(Refusal::AlreadySolved)
+        );
+        assert_eq!(check_attempt(&attempts, 2022, 1, 2, "500"), Ok(()));
+        assert_eq!(check_attempt(&attempts, 2021, 1, 1, "500"), Ok(()));
+    }
+
+    #[test]
+    fn test_attempt_roundtrip() {
+        let attempt = Attempt::new(2022, 9, 2, "36", Outcome::Wait { seconds: 60 });
+        let line = serde_json::to_string(&attempt).unwrap();
+        assert!(line.contains("\"result\":\"wait\",\"seconds\":60"));
+        assert_eq!(serde_json::from_str::<Attempt>(&line).unwrap(), attempt);
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, requests) = mock::serve(vec![(
+            200,
+            "<article><p>That's the right answer!</p></article>",
+        )]);
+        let client = Client::new(&url, "secret");
+
+        assert_eq!(
+            submit(&client, 2022, 5, 2, "MCD").unwrap(),
+            Outcome::Correct
+        );
+
+        let request = requests.recv().unwrap();
+        assert!(request.head.starts_with("POST /2022/day/5/answer HTTP/1.1"));
+        assert_eq!(request.header("Cookie"), Some("session=secret"));
+        assert_eq!(request.body, "level=2&answer=MCD");
+    }
+}
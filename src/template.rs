@@ -0,0 +1,329 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use regex::{Captures, Regex};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// Template for new days, relative to the project root. `cargo scaffold --template <path>` uses another file.
+pub const TEMPLATE_FILE: &str = "templates/day.rs.tpl";
+
+/// Answer types that `--answer` accepts.
+pub const ANSWER_TYPES: &[&str] = &[
+    "u32", "u64", "u128", "usize", "i32", "i64", "i128", "isize", "String", "bool",
+];
+
+/// How the template parses the input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Parser {
+    /// The input as is.
+    #[default]
+    String,
+    /// One `String` per line.
+    Lines,
+    /// One integer per line.
+    Ints,
+    /// A grid of characters, see [`crate::helpers::Grid`].
+    Grid,
+}
+
+impl Parser {
+    /// Type of the parsed input.
+    pub fn parsed(&self) -> &'static str {
+        match self {
+            Parser::String => "String",
+            Parser::Lines => "Vec<String>",
+            Parser::Ints => "Vec<i64>",
+            Parser::Grid => "crate::helpers::Grid<char>",
+        }
+    }
+
+    /// Type the parts borrow the parsed input as.
+    pub fn parsed_ref(&self) -> &'static str {
+        match self {
+            Parser::String => "str",
+            Parser::Lines => "[String]",
+            Parser::Ints => "[i64]",
+            Parser::Grid => "crate::helpers::Grid<char>",
+        }
+    }
+
+    /// Body of the `parse` function.
+    pub fn parse(&self) -> &'static str {
+        match self {
+            Parser::String => "input.to_string()",
+            Parser::Lines => "input.lines_typed().unwrap()",
+            Parser::Ints => "input.lines_typed().unwrap()",
+            // an empty example must not fail the tests of a freshly scaffolded day.
+            Parser::Grid => {
+                "if input.is_empty() {\n        return Default::default();\n    }\n    input.grid().unwrap()"
+            }
+        }
+    }
+}
+
+impl FromStr for Parser {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "string" => Ok(Parser::String),
+            "lines" => Ok(Parser::Lines),
+            "ints" => Ok(Parser::Ints),
+            "grid" => Ok(Parser::Grid),
+            _ => Err(format!(
+                "unknown parser \"{}\", expected string, lines, ints or grid",
+                s
+            )),
+        }
+    }
+}
+
+pub fn parse_answer_type(s: &str) -> Result<String, String> {
+    if ANSWER_TYPES.contains(&s) {
+        Ok(s.to_string())
+    } else {
+        Err(format!(
+            "unsupported answer type \"{}\", expected one of {}",
+            s,
+            ANSWER_TYPES.join(", ")
+        ))
+    }
+}
+
+/// Values for the placeholders of a template.
+#[derive(Clone, Debug)]
+pub struct Context {
+    pub day: u8,
+    pub year: i32,
+    pub answer_one: String,
+    pub answer_two: String,
+    pub parser: Parser,
+}
+
+impl Context {
+    fn values(&self) -> BTreeMap<&'static str, String> {
+        BTreeMap::from([
+            ("day", self.day.to_string()),
+            ("day_padded", format!("{:02}", self.day)),
+            ("year", self.year.to_string()),
+            ("answer_one", self.answer_one.clone()),
+            ("answer_two", self.answer_two.clone()),
+            ("parsed", self.parser.parsed().to_string()),
+            ("parsed_ref", self.parser.parsed_ref().to_string()),
+            ("parse", self.parser.parse().to_string()),
+        ])
+    }
+}
+
+/// Replaces `{{name}}` placeholders. Anything else, including `{name}` in format strings, is left alone.
+pub fn render(template: &str, context: &Context) -> Result<String, String> {
+    let placeholder = Regex::new(r"\{\{\s*(\w+)\s*\}\}").unwrap();
+    let values = context.values();
+
+    let mut unknown = vec![];
+    let rendered = placeholder.replace_all(template, |c: &Captures| match values.get(&c[1]) {
+        Some(value) => value.clone(),
+        None => {
+            unknown.push(c[1].to_string());
+            c[0].to_string()
+        }
+    });
+
+    if unknown.is_empty() {
+        Ok(rendered.into_owned())
+    } else {
+        Err(format!(
+            "unknown placeholders: {}. Available: {}",
+            unknown.join(", "),
+            values.keys().cloned().collect::<Vec<_>>().join(", ")
+        ))
+    }
+}
+
+/// Byte offset of the test block: the first line starting with `#[cfg(test)]` or `crate::example_tests!`.
+pub fn test_block_start(module: &str) -> Option<usize> {
+    let mut offset = 0;
+    for line in module.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("#[cfg(test)]") || trimmed.starts_with("crate::example_tests!") {
+            return Some(offset);
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Replaces the test block of `existing` with the one of `rendered`, keeping the solution code.
+pub fn update_test_block(existing: &str, rendered: &str) -> Result<String, String> {
+    let tests = test_block_start(rendered)
+        .map(|start| &rendered[start..])
+        .ok_or("the template has no test block")?;
+    let code = match test_block_start(existing) {
+        Some(start) => &existing[..start],
+        None => existing,
+    };
+
+    let code = code.trim_end();
+    Ok(format!("{}\n\n{}", code, tests))
+}
+
+/// Registry of a year without solutions, written by `cargo scaffold` for a new year.
+pub const YEAR_REGISTRY: &str = r#"/*
+ * Registry of the solutions for {{year}}. `cargo scaffold` adds new days here.
+ */
+use crate::solution::Day;
+
+pub const DAYS: &[Day] = &[];
+
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+"#;
+
+/// Splits the entries of an array literal at top-level commas.
+fn split_entries(array: &str) -> Vec<String> {
+    let mut entries = vec![];
+    let mut depth = 0;
+    let mut current = String::new();
+    for c in array.chars() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                entries.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => (),
+        }
+        current.push(c);
+    }
+    entries.push(current.trim().to_string());
+    entries.retain(|e| !e.is_empty());
+    entries
+}
+
+/// Declares `pub mod <module>;` in a registry and adds `entry` to the array constant `array`.
+/// Entries are sorted and written one per line. Returns `None` if the registry has an unexpected shape.
+pub fn add_to_registry(registry: &str, module: &str, array: &str, entry: &str) -> Option<String> {
+    let declaration = format!("pub mod {};", module);
+    let mut lines: Vec<String> = registry.lines().map(str::to_string).collect();
+    match lines.iter().rposition(|l| l.starts_with("pub mod ")) {
+        Some(last_mod) => lines.insert(last_mod + 1, declaration),
+        None => {
+            let last_use = lines.iter().rposition(|l| l.starts_with("use "))?;
+            lines.splice(last_use + 1..last_use + 1, [String::new(), declaration]);
+        }
+    }
+    let registry = lines.join("\n") + "\n";
+
+    let start = registry.find(&format!("pub const {}: ", array))?;
+    let open = start + registry[start..].find("= &[")? + "= &[".len();
+    let close = open + registry[open..].find("];")?;
+
+    let mut entries = split_entries(&registry[open..close]);
+    entries.push(entry.to_string());
+    entries.sort();
+    entries.dedup();
+
+    let mut body = String::from("\n");
+    for entry in entries {
+        body.push_str(&format!("    {},\n", entry));
+    }
+    Some(format!(
+        "{}{}{}",
+        &registry[..open],
+        body,
+        &registry[close..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(parser: Parser) -> Context {
+        Context {
+            day: 7,
+            year: 2022,
+            answer_one: "u64".to_string(),
+            answer_two: "String".to_string(),
+            parser,
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let template = "// {{year}} day {{ day }}\nfn a(x: &{{parsed_ref}}) -> Option<{{answer_two}}> { println!(\"{day} {DAY}\"); }\nstruct Day{{day_padded}};";
+        assert_eq!(
+            render(template, &context(Parser::Lines)).unwrap(),
+            "// 2022 day 7\nfn a(x: &[String]) -> Option<String> { println!(\"{day} {DAY}\"); }\nstruct Day07;"
+        );
+
+        let err = render("{{day}} {{nope}}", &context(Parser::String)).unwrap_err();
+        assert!(err.starts_with("unknown placeholders: nope."));
+    }
+
+    #[test]
+    fn test_default_template() {
+        let template = include_str!("../templates/day.rs.tpl");
+        let module = render(template, &context(Parser::Ints)).unwrap();
+        assert!(module.contains("pub fn parse(input: &Input) -> Vec<i64> {"));
+        assert!(module.contains("pub fn part_one(input: &[i64]) -> Option<u64> {"));
+        assert!(module.contains("const DAY: u8 = 7;"));
+        assert!(module.ends_with("crate::example_tests!(Day07);\n"));
+
+        let module = render(template, &context(Parser::Grid)).unwrap();
+        assert!(module.contains("    if input.is_empty() {\n        return Default::default();\n    }\n    input.grid().unwrap()\n}"));
+    }
+
+    #[test]
+    fn test_update_test_block() {
+        let existing = "fn solve() {}\n\n#[cfg(test)]\nmod tests {\n    // outdated\n}\n";
+        let rendered = "fn todo() {}\n\ncrate::example_tests!(Day07);\n";
+        assert_eq!(
+            update_test_block(existing, rendered).unwrap(),
+            "fn solve() {}\n\ncrate::example_tests!(Day07);\n"
+        );
+        assert_eq!(
+            update_test_block("fn solve() {}\n", rendered).unwrap(),
+            "fn solve() {}\n\ncrate::example_tests!(Day07);\n"
+        );
+        assert!(update_test_block(existing, "fn todo() {}\n").is_err());
+    }
+
+    #[test]
+    fn test_add_to_registry() {
+        let context = Context {
+            year: 2023,
+            ..context(Parser::String)
+        };
+        let registry = render(YEAR_REGISTRY, &context).unwrap();
+        let registry =
+            add_to_registry(&registry, "day07", "DAYS", "Day::new::<day07::Day07>()").unwrap();
+        assert!(registry.contains("use crate::solution::Day;\n\npub mod day07;\n\npub const DAYS: &[Day] = &[\n    Day::new::<day07::Day07>(),\n];\n"));
+
+        let registry =
+            add_to_registry(&registry, "day01", "DAYS", "Day::new::<day01::Day01>()").unwrap();
+        assert!(registry.contains("pub mod day07;\npub mod day01;\n"));
+        assert!(registry
+            .contains("&[\n    Day::new::<day01::Day01>(),\n    Day::new::<day07::Day07>(),\n];"));
+
+        let years = "use crate::solution::{Day, Year};\n\npub mod y2022;\n\npub const YEARS: &[Year] = &[Year::new(2022, y2022::DAYS)];\n";
+        assert_eq!(
+            add_to_registry(years, "y2021", "YEARS", "Year::new(2021, y2021::DAYS)").unwrap(),
+            "use crate::solution::{Day, Year};\n\npub mod y2022;\npub mod y2021;\n\npub const YEARS: &[Year] = &[\n    Year::new(2021, y2021::DAYS),\n    Year::new(2022, y2022::DAYS),\n];\n"
+        );
+        assert!(add_to_registry("fn main() {}", "day01", "DAYS", "x").is_none());
+    }
+
+    #[test]
+    fn test_parse_options() {
+        assert_eq!("grid".parse(), Ok(Parser::Grid));
+        assert!("json".parse::<Parser>().is_err());
+        assert_eq!(parse_answer_type("i64"), Ok("i64".to_string()));
+        assert!(parse_answer_type("f64").is_err());
+    }
+}
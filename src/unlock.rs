@@ -0,0 +1,274 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::aoc;
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use std::cell::RefCell;
+use std::thread;
+use std::time::Duration;
+
+/// Puzzles unlock at midnight EST, which is 05:00 UTC.
+pub const UNLOCK_HOUR_UTC: u32 = 5;
+
+/// Source of the current time. Injected so that waiting can be tested without actually waiting.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+    fn sleep(&self, duration: Duration);
+}
+
+/// The system clock.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration)
+    }
+}
+
+/// A clock that only advances when slept on.
+pub struct ManualClock {
+    now: RefCell<DateTime<Utc>>,
+    slept: RefCell<Vec<Duration>>,
+}
+
+impl ManualClock {
+    pub fn new(now: DateTime<Utc>) -> Self {
+        ManualClock {
+            now: RefCell::new(now),
+            slept: RefCell::new(vec![]),
+        }
+    }
+
+    /// Every duration slept so far.
+    pub fn slept(&self) -> Vec<Duration> {
+        self.slept.borrow().clone()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.borrow()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        *self.now.borrow_mut() += chrono::Duration::from_std(duration).unwrap();
+        self.slept.borrow_mut().push(duration);
+    }
+}
+
+/// Time at which a puzzle unlocks. Panics unless `day` is from 1 to 25.
+pub fn unlock_time(year: i32, day: u8) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, 12, day as u32, UNLOCK_HOUR_UTC, 0, 0)
+        .unwrap()
+}
+
+/// The next puzzle to unlock after `now`, as year and day, and its unlock time.
+pub fn next_unlock(now: DateTime<Utc>) -> (i32, u8, DateTime<Utc>) {
+    let year = now.year();
+    (1..=25)
+        .map(|day| (year, day, unlock_time(year, day)))
+        .find(|(_, _, time)| *time > now)
+        .unwrap_or_else(|| (year + 1, 1, unlock_time(year + 1, 1)))
+}
+
+/// Formats a countdown as `1d 02:03:04`, leaving out the days if there are none.
+/// Partial seconds are rounded up, so the countdown reads `00:00:00` only at unlock.
+pub fn format_countdown(remaining: Duration) -> String {
+    let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    let (days, hours, minutes, seconds) =
+        (secs / 86_400, secs / 3_600 % 24, secs / 60 % 60, secs % 60);
+    if days > 0 {
+        format!("{}d {:02}:{:02}:{:02}", days, hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+    }
+}
+
+/// Sleeps until `target`, calling `tick` with the remaining time about once per second.
+pub fn wait_until(clock: &impl Clock, target: DateTime<Utc>, mut tick: impl FnMut(Duration)) {
+    while let Ok(remaining) = (target - clock.now()).to_std() {
+        if remaining.is_zero() {
+            break;
+        }
+        tick(remaining);
+        // sleep to the next full second, so the countdown stays in sync with the clock.
+        let step = match remaining.subsec_nanos() {
+            0 => Duration::from_secs(1),
+            nanos => Duration::from_nanos(nanos as u64),
+        };
+        clock.sleep(step.min(remaining));
+    }
+}
+
+/// Exponential backoff between retries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Backoff {
+    pub initial: Duration,
+    pub max: Duration,
+    pub attempts: u32,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Backoff {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(60),
+            attempts: 12,
+        }
+    }
+}
+
+impl Backoff {
+    /// Delay after the failed `attempt`, counting from 1.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial.saturating_mul(factor).min(self.max)
+    }
+}
+
+/// Whether a request may succeed when it is repeated: the puzzle is not unlocked yet, or the server is busy.
+pub fn is_retryable(error: &aoc::Error) -> bool {
+    match error {
+        aoc::Error::Http { status, .. } => *status == 404 || *status >= 500,
+        aoc::Error::Transport(_) => true,
+        _ => false,
+    }
+}
+
+/// Calls `func` until it succeeds, fails with an error that is not retryable, or runs out of attempts.
+/// `on_retry` is called with the attempt, the error and the delay before every retry.
+pub fn retry<T>(
+    clock: &impl Clock,
+    backoff: &Backoff,
+    mut func: impl FnMut() -> Result<T, aoc::Error>,
+    mut on_retry: impl FnMut(u32, &aoc::Error, Duration),
+) -> Result<T, aoc::Error> {
+    let mut attempt = 1;
+    loop {
+        match func() {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt < backoff.attempts && is_retryable(&e) => {
+                let delay = backoff.delay(attempt);
+                on_retry(attempt, &e, delay);
+                clock.sleep(delay);
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2022, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_next_unlock() {
+        assert_eq!(
+            next_unlock(time(11, 20, 12, 0)),
+            (2022, 1, time(12, 1, 5, 0))
+        );
+        assert_eq!(
+            next_unlock(time(12, 1, 4, 59)),
+            (2022, 1, time(12, 1, 5, 0))
+        );
+        assert_eq!(next_unlock(time(12, 1, 5, 0)), (2022, 2, time(12, 2, 5, 0)));
+        assert_eq!(
+            next_unlock(time(12, 24, 23, 0)),
+            (2022, 25, time(12, 25, 5, 0))
+        );
+        assert_eq!(
+            next_unlock(time(12, 25, 5, 0)),
+            (2023, 1, Utc.with_ymd_and_hms(2023, 12, 1, 5, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(Duration::from_secs(59)), "00:00:59");
+        assert_eq!(
+            format_countdown(Duration::from_millis(3_722_500)),
+            "01:02:03"
+        );
+        assert_eq!(format_countdown(Duration::from_secs(93_784)), "1d 02:03:04");
+    }
+
+    #[test]
+    fn test_wait_until() {
+        let start = time(12, 7, 4, 59) + chrono::Duration::milliseconds(57_500);
+        let clock = ManualClock::new(start);
+        let mut ticks = vec![];
+        wait_until(&clock, time(12, 7, 5, 0), |remaining| {
+            ticks.push(format_countdown(remaining))
+        });
+
+        assert_eq!(clock.now(), time(12, 7, 5, 0));
+        assert_eq!(ticks, ["00:00:03", "00:00:02", "00:00:01"]);
+        assert_eq!(
+            clock.slept(),
+            [
+                Duration::from_millis(500),
+                Duration::from_secs(1),
+                Duration::from_secs(1)
+            ]
+        );
+
+        // a target in the past returns immediately.
+        wait_until(&clock, start, |_| panic!("should not tick"));
+    }
+
+    #[test]
+    fn test_retry() {
+        let clock = ManualClock::new(time(12, 7, 5, 0));
+        let backoff = Backoff {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(3),
+            attempts: 5,
+        };
+        let not_unlocked = || aoc::Error::Http {
+            status: 404,
+            body: String::new(),
+        };
+
+        let mut calls = 0;
+        let result = retry(
+            &clock,
+            &backoff,
+            || {
+                calls += 1;
+                if calls < 4 {
+                    Err(not_unlocked())
+                } else {
+                    Ok(calls)
+                }
+            },
+            |_, _, _| (),
+        );
+        assert_eq!(result.unwrap(), 4);
+        assert_eq!(clock.slept(), [1, 2, 3].map(Duration::from_secs).to_vec());
+
+        // attempts run out.
+        let result: Result<(), _> = retry(&clock, &backoff, || Err(not_unlocked()), |_, _, _| ());
+        assert!(matches!(result, Err(aoc::Error::Http { status: 404, .. })));
+        assert_eq!(clock.slept().len(), 3 + 4);
+
+        // an invalid session is not retried.
+        let result: Result<(), _> = retry(
+            &clock,
+            &backoff,
+            || Err(aoc::Error::MissingSession),
+            |_, _, _| panic!("should not retry"),
+        );
+        assert!(matches!(result, Err(aoc::Error::MissingSession)));
+    }
+}
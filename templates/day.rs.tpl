@@ -0,0 +1,39 @@
+// https://adventofcode.com/{{year}}/day/{{day}}
+use crate::{Input, Solution};
+
+pub fn parse(input: &Input) -> {{parsed}} {
+    {{parse}}
+}
+
+pub fn part_one(input: &{{parsed_ref}}) -> Option<{{answer_one}}> {
+    None
+}
+
+pub fn part_two(input: &{{parsed_ref}}) -> Option<{{answer_two}}> {
+    None
+}
+
+pub struct Day{{day_padded}};
+
+impl Solution for Day{{day_padded}} {
+    const YEAR: i32 = {{year}};
+    const DAY: u8 = {{day}};
+
+    type Parsed = {{parsed}};
+    type AnswerOne = {{answer_one}};
+    type AnswerTwo = {{answer_two}};
+
+    fn parse(input: &Input) -> Self::Parsed {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Parsed) -> Option<Self::AnswerOne> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Parsed) -> Option<Self::AnswerTwo> {
+        part_two(input)
+    }
+}
+
+crate::example_tests!(Day{{day_padded}});
@@ -0,0 +1,38 @@
+use advent_of_code::days::{self, y2022::day05};
+use advent_of_code::read_file;
+
+#[test]
+fn test_call_day_directly() {
+    let input = read_file("examples", 2022, 5);
+    let puzzle = day05::parse(&input);
+    assert_eq!(day05::part_one(&puzzle), Some("CMZ".to_string()));
+    assert_eq!(day05::part_two(&puzzle), Some("MCD".to_string()));
+}
+
+#[test]
+fn test_registry() {
+    // checks invariants only, so scaffolding a new day or year keeps this green.
+    let years = days::years();
+    assert!(
+        years.windows(2).all(|w| w[0] < w[1]),
+        "years are not sorted and unique: {:?}",
+        years
+    );
+    for year in days::YEARS {
+        let registered: Vec<u8> = year.days.iter().map(|d| d.day).collect();
+        assert!(
+            registered.windows(2).all(|w| w[0] < w[1]),
+            "days of {} are not sorted and unique: {:?}",
+            year.year,
+            registered
+        );
+        for day in year.days {
+            assert_eq!(day.year, year.year);
+            assert!((1..=25).contains(&day.day), "{} day {}", day.year, day.day);
+            let found = days::get(year.year, day.day).unwrap();
+            assert_eq!((found.year, found.day), (day.year, day.day));
+        }
+    }
+    assert!(days::get(2022, 26).is_none());
+    assert!(days::get(0, 5).is_none());
+}